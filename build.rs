@@ -1,3 +1,12 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+#[path = "xtask/src/csv_rows.rs"]
+mod csv_rows;
+use csv_rows::for_each_row;
+
 fn main() {
     // Descobre o SO de destino (não o do host!)
     let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
@@ -12,32 +21,299 @@ fn main() {
         }
     }
 
-    let cfg = slint_build::CompilerConfiguration::new().with_style("cosmic-dark".into());
-    slint_build::compile_with_config("ui/app.slint", cfg).expect("Failed to compile Slint UI");
+    // A UI só é compilada com a feature `gui`; o estilo segue o tema escolhido (Theme)
+    #[cfg(feature = "gui")]
+    {
+        let cfg = slint_build::CompilerConfiguration::new().with_style("cosmic".into());
+        slint_build::compile_with_config("ui/app.slint", cfg).expect("Failed to compile Slint UI");
+    }
+
+    // Erros de dados param o build apontando arquivo e linha
+    if let Err(e) = generate_tables() {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
+
+const POKEMON_MAIN: &str = "data/pokemon_main.csv"; // ajuste se necessário
+const POKEMON_SPECIES: &str = "data/pokemon_species.csv";
+const POKEMON_COLORS: &str = "data/pokemon_colors.csv";
+const ABILITIES: &str = "data/abilities.csv";
+const ABILITY_NAMES: &str = "data/ability_names.csv";
+const POKEMON_FORMS: &str = "data/pokemon.csv";
+const POKEMON_ABILITIES: &str = "data/pokemon_abilities.csv";
+const TYPES: &str = "data/types.csv";
+const POKEMON_TYPES: &str = "data/pokemon_types.csv";
+
+/// Gerações conhecidas (ver `helpers::REGIONS`)
+const GENERATIONS: u8 = 9;
+
+/// Idioma dos nomes de habilidade (9 = inglês)
+const ENGLISH: &str = "9";
+
+fn generate_tables() -> Result<(), String> {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=xtask/src/csv_rows.rs");
+    for path in [
+        POKEMON_MAIN,
+        POKEMON_SPECIES,
+        POKEMON_COLORS,
+        ABILITIES,
+        ABILITY_NAMES,
+        POKEMON_FORMS,
+        POKEMON_ABILITIES,
+        TYPES,
+        POKEMON_TYPES,
+    ] {
+        println!("cargo:rerun-if-changed={path}");
+    }
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    let out_dir = Path::new(&out_dir);
+    let write = |name: &str, code: String| {
+        std::fs::write(out_dir.join(name), code).map_err(|e| format!("{name}: {e}"))
+    };
+    let colors = pokemon_colors()?;
+    write("pokemon_color.rs", pokemon_color_enum(&colors))?;
+    write("pokemon_list.rs", pokemon_list(&colors)?)?;
+    write("ability_list.rs", ability_list()?)?;
+    Ok(())
+}
+
+// =================== Tabelas geradas ===================
+/// Cores de pokemon_colors.csv: (id, nome da variante do enum)
+fn pokemon_colors() -> Result<Vec<(String, String)>, String> {
+    let mut colors = Vec::new();
+    for_each_row(POKEMON_COLORS, true, |row| {
+        let id = row.get("id")?;
+        row.parse::<u8>("id")?;
+        let identifier = row.get("identifier")?;
+        // partes vazias ("light-", "-blue", "a--b") não viram nome de variante
+        if identifier.split('-').any(str::is_empty)
+            || !identifier
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c == '-')
+        {
+            return Err(row.err(format!("identificador de cor inválido: \"{identifier}\"")));
+        }
+        // "light-blue" -> "LightBlue"
+        let variant: String = identifier
+            .split('-')
+            .map(|part| part[..1].to_ascii_uppercase() + &part[1..])
+            .collect();
+        colors.push((id.to_string(), variant));
+        Ok(())
+    })?;
+    Ok(colors)
+}
+
+fn pokemon_color_enum(colors: &[(String, String)]) -> String {
+    let mut out = String::new();
+    out.push_str("// Auto-generated by build.rs\n");
+    out.push_str("/// Cor da Pokédex (pokemon_colors.csv)\n");
+    out.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+    out.push_str("pub enum PokemonColor {\n");
+    for (_, variant) in colors {
+        out.push_str(&format!("    {variant},\n"));
+    }
+    out.push_str("}\n\n");
+    out.push_str("impl PokemonColor {\n");
+    out.push_str(&format!(
+        "    pub const ALL: [PokemonColor; {}] = [\n",
+        colors.len()
+    ));
+    for (_, variant) in colors {
+        out.push_str(&format!("        PokemonColor::{variant},\n"));
+    }
+    out.push_str("    ];\n\n");
+    out.push_str("    /// Id em pokemon_colors.csv\n");
+    out.push_str("    pub fn id(self) -> u8 {\n        match self {\n");
+    for (id, variant) in colors {
+        out.push_str(&format!("            PokemonColor::{variant} => {id},\n"));
+    }
+    out.push_str("        }\n    }\n}\n");
+    out
+}
+
+/// Tipos de cada Pokémon (pokemon_types.csv), já como variantes de `PokemonType`, na ordem do slot
+fn pokemon_types() -> Result<HashMap<u32, Vec<String>>, String> {
+    // "fire" -> "Fire"
+    let mut names: HashMap<String, String> = HashMap::new();
+    for_each_row(TYPES, true, |row| {
+        let id = row.get("id")?;
+        row.parse::<u32>("id")?;
+        let identifier = row.get("identifier")?;
+        if identifier.is_empty() || !identifier.chars().all(|c| c.is_ascii_lowercase()) {
+            return Err(row.err(format!("identificador de tipo inválido: \"{identifier}\"")));
+        }
+        names.insert(
+            id.to_string(),
+            identifier[..1].to_ascii_uppercase() + &identifier[1..],
+        );
+        Ok(())
+    })?;
+
+    let mut slots: HashMap<u32, Vec<(u8, String)>> = HashMap::new();
+    for_each_row(POKEMON_TYPES, true, |row| {
+        let id: u32 = row.parse("pokemon_id")?;
+        let type_id = row.get("type_id")?;
+        let slot: u8 = row.parse("slot")?;
+        let Some(name) = names.get(type_id) else {
+            return Err(row.err(format!("tipo \"{type_id}\" não existe em {TYPES}")));
+        };
+        let types = slots.entry(id).or_default();
+        if !(1..=2).contains(&slot) || types.iter().any(|(s, _)| *s == slot) {
+            return Err(row.err(format!("slot inválido ou repetido para o id {id}: {slot}")));
+        }
+        types.push((slot, name.clone()));
+        Ok(())
+    })?;
+
+    Ok(slots
+        .into_iter()
+        .map(|(id, mut types)| {
+            types.sort();
+            (id, types.into_iter().map(|(_, name)| name).collect())
+        })
+        .collect())
+}
 
-    let csv_path = "data/pokemon_main.csv"; // ajuste se necessário
-    let csv = std::fs::read_to_string(csv_path).expect("failed to read CSV");
+fn pokemon_list(colors: &[(String, String)]) -> Result<String, String> {
+    let colors: HashMap<&str, &str> = colors
+        .iter()
+        .map(|(id, variant)| (id.as_str(), variant.as_str()))
+        .collect();
+    let types = pokemon_types()?;
+
+    // has_gender_differences e geração por espécie (pokemon_species.csv)
+    let mut gender_diff = HashSet::new();
+    let mut generations: HashMap<u32, u8> = HashMap::new();
+    for_each_row(POKEMON_SPECIES, true, |row| {
+        let id = row.parse::<u32>("id")?;
+        if row.get("has_gender_differences")? == "1" {
+            gender_diff.insert(id);
+        }
+        let generation: u8 = row.parse("generation_id")?;
+        if !(1..=GENERATIONS).contains(&generation) {
+            return Err(row.err(format!("geração fora de 1..={GENERATIONS}: {generation}")));
+        }
+        generations.insert(id, generation);
+        Ok(())
+    })?;
 
     let mut out = String::new();
     out.push_str("// Auto-generated by build.rs\n");
     out.push_str("#[derive(Copy, Clone)]\n");
-    out.push_str("pub struct Pokemon {\n    pub id: u32,\n    pub name: &'static str,\n    pub color: &'static str,\n}\n");
+    out.push_str("pub struct Pokemon {\n    pub id: u32,\n    pub name: &'static str,\n    pub color: PokemonColor,\n    pub has_gender_differences: bool,\n    pub generation: u8,\n    pub types: &'static [PokemonType],\n}\n");
     out.push_str("pub const POKEMON_LIST: &[Pokemon] = &[\n");
 
-    for line in csv.lines() {
-        let mut cols = line.split(',');
-        let id = cols.next().unwrap().trim();
-        let name = cols.next().unwrap().trim();
-        let color = cols.next().unwrap().trim();
+    // ids precisam ser 1, 2, 3... sem repetir nem pular
+    let mut expected = 1;
+    for_each_row(POKEMON_MAIN, false, |row| {
+        let id: u32 = row.parse_at(0, "id")?;
+        let name = row.at(1)?;
+        let color = row.at(2)?;
+
+        if id < expected {
+            return Err(row.err(format!(
+                "id {id} repetido ou fora de ordem (esperava {expected})"
+            )));
+        }
+        if id != expected {
+            return Err(row.err(format!("esperava id {expected}, encontrou {id}")));
+        }
+        expected += 1;
+        if name.is_empty() {
+            return Err(row.err("nome vazio"));
+        }
+        if name.contains(char::REPLACEMENT_CHARACTER) {
+            return Err(row.err(format!("nome com caractere inválido (encoding?): {name:?}")));
+        }
+        let Some(color) = colors.get(color) else {
+            return Err(row.err(format!("cor \"{color}\" não existe em {POKEMON_COLORS}")));
+        };
+        let Some(generation) = generations.get(&id) else {
+            return Err(row.err(format!("id {id} não existe em {POKEMON_SPECIES}")));
+        };
+        let Some(types) = types.get(&id) else {
+            return Err(row.err(format!("id {id} sem tipos em {POKEMON_TYPES}")));
+        };
+        let types: Vec<String> = types.iter().map(|t| format!("PokemonType::{t}")).collect();
+
+        // {:?} gera um literal Rust com aspas/barras escapadas
         out.push_str(&format!(
-            "    Pokemon {{ id: {}, name: \"{}\", color: \"{}\" }},\n",
-            id, name, color
+            "    Pokemon {{ id: {id}, name: {name:?}, color: PokemonColor::{color}, has_gender_differences: {}, generation: {generation}, types: &[{}] }},\n",
+            gender_diff.contains(&id),
+            types.join(", ")
         ));
-    }
+        Ok(())
+    })?;
 
     out.push_str("];\n");
+    Ok(out)
+}
 
-    // gera em OUT_DIR
-    let out_dir = std::env::var("OUT_DIR").unwrap();
-    std::fs::write(std::path::Path::new(&out_dir).join("pokemon_list.rs"), out).unwrap();
+/// Habilidades da série principal com o nome em inglês e as de cada espécie
+fn ability_list() -> Result<String, String> {
+    let mut main_series = HashSet::new();
+    for_each_row(ABILITIES, true, |row| {
+        if row.get("is_main_series")? == "1" {
+            main_series.insert(row.parse::<u32>("id")?);
+        }
+        Ok(())
+    })?;
+
+    let mut out = String::new();
+    out.push_str("// Auto-generated by build.rs\n");
+    out.push_str("pub const ABILITY_NAMES: &[&str] = &[\n");
+    let mut names: HashMap<u32, String> = HashMap::new();
+    for_each_row(ABILITY_NAMES, true, |row| {
+        let id: u32 = row.parse("ability_id")?;
+        if row.get("local_language_id")? == ENGLISH && main_series.contains(&id) {
+            let name = row.get("name")?;
+            out.push_str(&format!("    {name:?},\n"));
+            names.insert(id, name.to_string());
+        }
+        Ok(())
+    })?;
+    out.push_str("];\n\n");
+
+    // formas ("Ninetales-Alola") somam as habilidades na espécie base
+    let mut species_of: HashMap<u32, u32> = HashMap::new();
+    for_each_row(POKEMON_FORMS, true, |row| {
+        species_of.insert(row.parse("id")?, row.parse("species_id")?);
+        Ok(())
+    })?;
+    let mut by_species: HashMap<u32, Vec<&str>> = HashMap::new();
+    for_each_row(POKEMON_ABILITIES, true, |row| {
+        let pokemon_id: u32 = row.parse("pokemon_id")?;
+        let Some(species) = species_of.get(&pokemon_id) else {
+            return Err(row.err(format!(
+                "pokemon_id {pokemon_id} não existe em {POKEMON_FORMS}"
+            )));
+        };
+        let ability_id: u32 = row.parse("ability_id")?;
+        let Some(name) = names.get(&ability_id) else {
+            return Err(row.err(format!(
+                "ability_id {ability_id} sem nome em {ABILITY_NAMES}"
+            )));
+        };
+        let list = by_species.entry(*species).or_default();
+        if !list.contains(&name.as_str()) {
+            list.push(name);
+        }
+        Ok(())
+    })?;
+
+    out.push_str(
+        "/// Habilidades possíveis de cada espécie (todas as formas), na posição `id - 1`\n",
+    );
+    out.push_str("pub const SPECIES_ABILITIES: &[&[&str]] = &[\n");
+    let last = by_species.keys().copied().max().unwrap_or(0);
+    for id in 1..=last {
+        let list = by_species.get(&id).map(Vec::as_slice).unwrap_or_default();
+        out.push_str(&format!("    &{list:?},\n"));
+    }
+    out.push_str("];\n");
+    Ok(out)
 }
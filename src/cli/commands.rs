@@ -0,0 +1,295 @@
+use slindex::{
+    POKEMON_LIST, Pokedex, Pokemon, find_pokemon,
+    helpers::{cap_words_and_spaces, generation_label, stat_label_pt, type_label_pt},
+    search_pokemon,
+    service::Detail,
+};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Uso: slindex-cli [--json] <comando> [argumentos]
+
+Comandos:
+  show <id|nome>              detalhes de um Pokémon
+  search <texto>              busca por número ou nome
+  types <id|nome>             tipos de um Pokémon
+  compare <id|nome> <id|nome> compara os stats de dois Pokémon
+  export [--format csv|json]  exporta a lista completa
+  help                        mostra esta ajuda
+
+Opções:
+  --json                      saída em JSON";
+
+pub fn main() -> ExitCode {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let json = args.iter().any(|a| a == "--json");
+    args.retain(|a| a != "--json");
+
+    match run(&args, json) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("erro: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String], json: bool) -> Result<(), String> {
+    let arg = |i: usize| {
+        args.get(i)
+            .map(String::as_str)
+            .ok_or_else(|| format!("argumento ausente\n\n{USAGE}"))
+    };
+
+    match args.first().map(String::as_str) {
+        Some("show") => {
+            let pokemon = lookup(arg(1)?)?;
+            let detail = fetch(&[pokemon])?.remove(0);
+            print_out(json, detail_json(&pokemon, &detail), || {
+                format_detail(&pokemon, &detail)
+            });
+        }
+        Some("search") => {
+            let found = search_pokemon(&args[1..].join(" "));
+            let list: Vec<_> = found.iter().map(pokemon_json).collect();
+            print_out(json, serde_json::Value::Array(list), || {
+                found
+                    .iter()
+                    .map(|p| format!("{} - {}", p.id, p.name))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            });
+        }
+        Some("types") => {
+            let pokemon = lookup(arg(1)?)?;
+            let detail = fetch(&[pokemon])?.remove(0);
+            let value = serde_json::json!({ "id": pokemon.id, "name": pokemon.name, "types": detail.types });
+            print_out(json, value, || {
+                let labels: Vec<&str> = detail.types.iter().map(|t| type_label_pt(*t)).collect();
+                format!("{}: {}", pokemon.name, labels.join(" / "))
+            });
+        }
+        Some("compare") => {
+            let pair = [lookup(arg(1)?)?, lookup(arg(2)?)?];
+            let details = fetch(&pair)?;
+            let value = serde_json::Value::Array(
+                pair.iter()
+                    .zip(&details)
+                    .map(|(p, d)| detail_json(p, d))
+                    .collect(),
+            );
+            print_out(json, value, || format_compare(&pair, &details));
+        }
+        Some("export") => {
+            let format = match args.get(1).map(String::as_str) {
+                None if json => "json",
+                None => "csv",
+                Some("--format") => arg(2)?,
+                Some(other) => return Err(format!("opção desconhecida: {other}")),
+            };
+            match format {
+                "csv" => print!("{}", export_csv(POKEMON_LIST)?),
+                "json" => println!("{:#}", export_json(POKEMON_LIST)),
+                other => return Err(format!("formato desconhecido: {other}")),
+            }
+        }
+        None | Some("help") | Some("--help") | Some("-h") => println!("{USAGE}"),
+        Some(other) => return Err(format!("comando desconhecido: {other}\n\n{USAGE}")),
+    }
+    Ok(())
+}
+
+fn lookup(query: &str) -> Result<Pokemon, String> {
+    find_pokemon(query).ok_or_else(|| format!("Pokémon não encontrado: {query}"))
+}
+
+/// Busca os detalhes na PokeAPI (em paralelo quando há mais de um)
+fn fetch(pokemons: &[Pokemon]) -> Result<Vec<Detail>, String> {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| e.to_string())?;
+    let pokedex = Pokedex::new();
+    rt.block_on(async {
+        let mut tasks = Vec::with_capacity(pokemons.len());
+        for p in pokemons {
+            let pokedex = pokedex.clone();
+            let id = p.id;
+            tasks.push(tokio::spawn(async move { pokedex.detail(id).await }));
+        }
+        let mut details = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            details.push(task.await.map_err(|e| e.to_string())??);
+        }
+        Ok(details)
+    })
+}
+
+fn print_out(json: bool, value: serde_json::Value, text: impl FnOnce() -> String) {
+    if json {
+        println!("{value:#}");
+    } else {
+        println!("{}", text());
+    }
+}
+
+fn pokemon_json(p: &Pokemon) -> serde_json::Value {
+    serde_json::json!({ "id": p.id, "name": p.name, "color": p.color.id(), "generation": p.generation })
+}
+
+fn detail_json(p: &Pokemon, d: &Detail) -> serde_json::Value {
+    let stats: serde_json::Map<String, serde_json::Value> = d
+        .stats
+        .iter()
+        .map(|(k, v)| (k.id().to_string(), (*v).into()))
+        .collect();
+    serde_json::json!({
+        "id": d.id,
+        "name": p.name,
+        "height": d.height,
+        "weight": d.weight,
+        "types": d.types,
+        "abilities": [d.ability1, d.ability2],
+        "hidden_ability": d.hidden_ability,
+        "stats": stats,
+        "total": d.stats.iter().map(|(_, v)| v).sum::<u32>(),
+        "artwork_url": d.artwork_url(),
+    })
+}
+
+fn format_detail(p: &Pokemon, d: &Detail) -> String {
+    let types: Vec<&str> = d.types.iter().map(|t| type_label_pt(*t)).collect();
+    let abilities: Vec<String> = [&d.ability1, &d.ability2]
+        .into_iter()
+        .filter(|a| !a.is_empty())
+        .map(|a| cap_words_and_spaces(a))
+        .collect();
+
+    let mut out = format!(
+        "#{} {} ({})\n",
+        d.id,
+        p.name,
+        generation_label(p.generation)
+    );
+    out.push_str(&format!("Tipos: {}\n", types.join(" / ")));
+    out.push_str(&format!(
+        "Altura: {} m  Peso: {} kg\n",
+        d.height as f32 / 10.0,
+        d.weight as f32 / 10.0
+    ));
+    out.push_str(&format!("Habilidades: {}\n", abilities.join(", ")));
+    if !d.hidden_ability.is_empty() {
+        out.push_str(&format!(
+            "Hidden: {}\n",
+            cap_words_and_spaces(&d.hidden_ability)
+        ));
+    }
+    for (k, v) in &d.stats {
+        out.push_str(&format!("{:<16} {v:>3}\n", stat_label_pt(*k)));
+    }
+    let total: u32 = d.stats.iter().map(|(_, v)| v).sum();
+    out.push_str(&format!("{:<16} {total:>3}", "Total"));
+    out
+}
+
+fn format_compare(pair: &[Pokemon; 2], details: &[Detail]) -> String {
+    let (a, b) = (&details[0], &details[1]);
+    let mut out = format!(
+        "{:<16} {:>12} {:>12} {:>6}\n",
+        "", pair[0].name, pair[1].name, "Dif."
+    );
+    for ((k, va), (_, vb)) in a.stats.iter().zip(&b.stats) {
+        let diff = *va as i64 - *vb as i64;
+        out.push_str(&format!(
+            "{:<16} {va:>12} {vb:>12} {diff:>+6}\n",
+            stat_label_pt(*k)
+        ));
+    }
+    let ta: u32 = a.stats.iter().map(|(_, v)| v).sum();
+    let tb: u32 = b.stats.iter().map(|(_, v)| v).sum();
+    out.push_str(&format!(
+        "{:<16} {ta:>12} {tb:>12} {:>+6}",
+        "Total",
+        ta as i64 - tb as i64
+    ));
+    out
+}
+
+/// CSV com cabeçalho, com as mesmas regras de aspas dos arquivos de `data/`
+fn export_csv(pokemons: &[Pokemon]) -> Result<String, String> {
+    let mut writer = csv::WriterBuilder::new()
+        .terminator(csv::Terminator::Any(b'\n'))
+        .from_writer(Vec::new());
+    writer
+        .write_record(["id", "name", "color", "generation"])
+        .map_err(|e| e.to_string())?;
+    for p in pokemons {
+        writer
+            .write_record([
+                p.id.to_string(),
+                p.name.to_string(),
+                p.color.id().to_string(),
+                p.generation.to_string(),
+            ])
+            .map_err(|e| e.to_string())?;
+    }
+    let bytes = writer.into_inner().map_err(|e| e.to_string())?;
+    String::from_utf8(bytes).map_err(|e| e.to_string())
+}
+
+fn export_json(pokemons: &[Pokemon]) -> serde_json::Value {
+    serde_json::Value::Array(pokemons.iter().map(pokemon_json).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use slindex::PokemonColor;
+
+    fn named(name: &'static str) -> Pokemon {
+        Pokemon {
+            name,
+            ..POKEMON_LIST[0]
+        }
+    }
+
+    #[test]
+    fn csv_quotes_what_needs_quoting() {
+        let list = [
+            named("Mr. Mime"),
+            named("Vírgula, \"aspas\""),
+            named("duas\nlinhas"),
+        ];
+        let text = export_csv(&list).unwrap();
+        assert!(
+            text.starts_with("id,name,color,generation\n1,Mr. Mime,5,1\n"),
+            "{text}"
+        );
+        assert!(text.contains("\"Vírgula, \"\"aspas\"\"\""), "{text}");
+
+        let mut reader = csv::Reader::from_reader(text.as_bytes());
+        let names: Vec<String> = reader
+            .records()
+            .map(|r| r.unwrap()[1].to_string())
+            .collect();
+        assert_eq!(names, list.map(|p| p.name));
+    }
+
+    #[test]
+    fn csv_has_one_row_per_pokemon() {
+        let text = export_csv(POKEMON_LIST).unwrap();
+        assert_eq!(text.lines().count(), POKEMON_LIST.len() + 1);
+    }
+
+    #[test]
+    fn json_export_shape() {
+        let value = export_json(&POKEMON_LIST[..2]);
+        let bulbasaur = &value[0];
+        assert_eq!(value.as_array().unwrap().len(), 2);
+        assert_eq!(bulbasaur["id"], 1);
+        assert_eq!(bulbasaur["name"], "Bulbasaur");
+        assert_eq!(bulbasaur["color"], PokemonColor::Green.id());
+        assert_eq!(bulbasaur["generation"], 1);
+        assert_eq!(bulbasaur.as_object().unwrap().len(), 4);
+    }
+}
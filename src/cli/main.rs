@@ -0,0 +1,11 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod commands;
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> std::process::ExitCode {
+    commands::main()
+}
+
+// Para wasm32 não existe CLI (depende de tokio)
+#[cfg(target_arch = "wasm32")]
+fn main() {}
@@ -0,0 +1,885 @@
+use crate::{
+    POKEMON_LIST, Pokedex, Pokemon, PokemonType,
+    helpers::{
+        Language, REGIONS, Units, cap_words_and_spaces, format_height, format_weight,
+        generation_label, generation_roman, stat_abbrev_pt, stat_label_pt, type_label_pt,
+    },
+    service, showdown,
+};
+use artwork::Artwork;
+use controller::{Controller, View};
+use drill::{CHOICE_LABELS, DrillStats, Matchup, accuracy_pt};
+use executor::Executor;
+use helpers::*;
+use quiz::QuizScore;
+use route::{Page, Route};
+use settings::{CacheLimits, PaletteChoice, PlatformSettings, ThemeChoice};
+use size::{Figure, HUMAN_HEIGHT, SizeReference};
+use slint::{Brush, Color, ComponentHandle, Model, ModelRc, SharedString, VecModel};
+
+mod artwork;
+mod controller;
+mod drill;
+mod executor;
+mod helpers;
+mod quiz;
+mod route;
+mod settings;
+mod size;
+mod source;
+mod thumbs;
+slint::include_modules!(); // App, PokemonRow, PokemonDetail, TypeTag, StatBar...
+
+/// Lado da artwork no DetailPanel, em pixels lógicos (ver app.slint)
+const ARTWORK_SIZE: f32 = 250.0;
+
+/// Cor de fundo quando o Pokémon não está na lista embutida
+const NO_COLOR: Color = Color::from_rgb_u8(0, 0, 0);
+
+// =================== UI Utils ===================
+fn type_tag(t: PokemonType, palette: PaletteChoice) -> TypeTag {
+    TypeTag {
+        label: type_label_pt(t).into(),
+        bg: type_color(t, palette),
+        icon: type_icon(t),
+    }
+}
+
+fn type_tags(types: &[PokemonType], palette: PaletteChoice) -> ModelRc<TypeTag> {
+    let tags: Vec<TypeTag> = types.iter().map(|t| type_tag(*t, palette)).collect();
+    ModelRc::new(VecModel::from(tags))
+}
+
+/// Linhas sem miniatura; cada uma pede a sua ao aparecer (`request-thumb`)
+fn set_rows_from_pokemon(app: &App, pokemons: &[Pokemon], palette: PaletteChoice) {
+    let rows: Vec<PokemonRow> = pokemons
+        .iter()
+        .map(|pokemon| PokemonRow {
+            id: pokemon.id as i32,
+            number: format!("#{:04}", pokemon.id).into(),
+            name: pokemon.name.into(),
+            types: type_tags(pokemon.types, palette),
+            thumb: slint::Image::default(),
+            color: pokemon_color(pokemon.color),
+        })
+        .collect();
+    app.set_result_count(rows.len() as i32);
+    app.set_rows(ModelRc::new(VecModel::from(rows)));
+
+    // atalhos só para as gerações presentes na lista filtrada
+    let mut generations: Vec<u8> = pokemons.iter().map(|p| p.generation).collect();
+    generations.sort_unstable();
+    generations.dedup();
+    let tabs: Vec<GenerationTab> = generations
+        .into_iter()
+        .map(|g| GenerationTab {
+            number: g as i32,
+            label: generation_roman(g).into(),
+        })
+        .collect();
+    app.set_generations(ModelRc::new(VecModel::from(tabs)));
+}
+
+fn make_detail_for_ui(
+    detail: &service::Detail,
+    artwork: Option<&Artwork>,
+    palette: PaletteChoice,
+    units: Units,
+    language: Language,
+) -> PokemonDetail {
+    // Monta chips de tipo
+    let types_model = type_tags(&detail.types, palette);
+
+    // Monta stats
+    let mut total: i32 = 0;
+    let mut stats_vec: Vec<StatBar> = Vec::with_capacity(detail.stats.len());
+    for (k, v) in &detail.stats {
+        total += *v as i32;
+        stats_vec.push(StatBar {
+            name: stat_label_pt(*k).into(),
+            abbrev: stat_abbrev_pt(*k).into(),
+            value: *v as i32,
+            bg: stat_color(*k, palette),
+        });
+    }
+    let stats_model = ModelRc::new(VecModel::from(stats_vec));
+
+    // Artwork (já decodificada fora da UI); animações vão quadro a quadro
+    let artwork_img = artwork.map(Artwork::to_image).unwrap_or_default();
+    let (frames, delays): (Vec<slint::Image>, Vec<i32>) = artwork
+        .map(Artwork::frames)
+        .unwrap_or_default()
+        .into_iter()
+        .unzip();
+
+    let pokemon = POKEMON_LIST.iter().find(|p| p.id == detail.id);
+    let name = pokemon.map(|p| p.name).unwrap_or_default();
+    // lido pelo leitor de tela: "Pikachu, nº 25, tipo Elétrico, total 320"
+    let type_labels: Vec<&str> = detail.types.iter().map(|t| type_label_pt(*t)).collect();
+    let announcement = format!(
+        "{name}, nº {}, tipo {}, total {total}",
+        detail.id,
+        type_labels.join(" e ")
+    );
+    PokemonDetail {
+        name: name.into(),
+        id: detail.id as i32,
+        height: detail.height as i32,
+        weight: detail.weight as i32,
+        heightText: format_height(detail.height, units, language).into(),
+        weightText: format_weight(detail.weight, units, language).into(),
+        types: types_model,
+        stats: stats_model,
+        artwork: artwork_img,
+        frames: ModelRc::new(VecModel::from(frames)),
+        frameDelays: ModelRc::new(VecModel::from(delays)),
+        total,
+        ability1: cap_words_and_spaces(&detail.ability1).into(),
+        ability2: cap_words_and_spaces(&detail.ability2).into(),
+        hiddenAbility: cap_words_and_spaces(&detail.hidden_ability).into(),
+        error: "".into(),
+        color: pokemon.map_or(NO_COLOR.into(), |p| pokemon_color(p.color)),
+        genderDifferences: pokemon.is_some_and(|p| p.has_gender_differences),
+        generation: pokemon
+            .map(|p| generation_label(p.generation))
+            .unwrap_or_default()
+            .into(),
+        announcement: announcement.into(),
+    }
+}
+
+/// Proporções e legendas da comparação de tamanho com o detalhe já mostrado
+fn make_size_for_ui(
+    detail: &PokemonDetail,
+    reference: Option<&SizeReference>,
+    units: Units,
+    language: Language,
+) -> SizeCompare {
+    let (name, height, image) = match reference {
+        None => ("Pessoa", HUMAN_HEIGHT, slint::Image::default()),
+        Some(r) => (
+            r.pokemon.name,
+            r.height,
+            r.artwork
+                .as_ref()
+                .map(Artwork::to_image)
+                .unwrap_or_default(),
+        ),
+    };
+    let comparison = size::compare(u32::try_from(detail.height).unwrap_or(0), height);
+    let zoom = |figure: Figure| {
+        if figure.zoom > 1.0 {
+            format!("ampliado {}", size::times(figure.zoom, language))
+        } else {
+            String::new()
+        }
+    };
+    let summary = match comparison {
+        Some(c) if reference.is_none() => {
+            size::summary(&detail.name, "uma pessoa", c.ratio, language)
+        }
+        Some(c) => size::summary(&detail.name, name, c.ratio, language),
+        None if height == 0 => format!("Carregando {name}..."),
+        None => "Altura desconhecida".to_string(),
+    };
+    SizeCompare {
+        subject: comparison.map_or(0.0, |c| c.subject.fraction),
+        reference: comparison.map_or(0.0, |c| c.reference.fraction),
+        subject_zoom: comparison
+            .map(|c| zoom(c.subject))
+            .unwrap_or_default()
+            .into(),
+        reference_zoom: comparison
+            .map(|c| zoom(c.reference))
+            .unwrap_or_default()
+            .into(),
+        human: reference.is_none(),
+        reference_name: name.into(),
+        reference_height: if height == 0 {
+            "".into()
+        } else {
+            format_height(height, units, language).into()
+        },
+        reference_image: image,
+        summary: summary.into(),
+        error: "".into(),
+    }
+}
+
+fn make_drill_for_ui(
+    matchup: &Matchup,
+    chosen: Option<usize>,
+    stats: &DrillStats,
+    palette: PaletteChoice,
+) -> DrillQuestion {
+    let tag = |t: PokemonType| type_tag(t, palette);
+    let defenders: Vec<TypeTag> = matchup.defenders().into_iter().map(tag).collect();
+    let choices: Vec<SharedString> = CHOICE_LABELS.iter().map(|&c| c.into()).collect();
+
+    let answer = matchup.answer();
+    let feedback = match chosen {
+        None => String::new(),
+        Some(c) if c == answer => format!("Certo! {}", CHOICE_LABELS[answer]),
+        Some(_) => format!("Errado: é {}", CHOICE_LABELS[answer]),
+    };
+    let current = stats.get(matchup);
+    let record = if current.answered == 0 {
+        "Confronto novo".to_string()
+    } else {
+        format!(
+            "Este confronto: {}",
+            accuracy_pt(current.correct, current.answered)
+        )
+    };
+    let (correct, answered) = stats
+        .matchups
+        .values()
+        .fold((0, 0), |(c, a), s| (c + s.correct, a + s.answered));
+    let hardest: Vec<SharedString> = stats
+        .hardest()
+        .into_iter()
+        .map(|(m, s)| format!("{}: {}", m.label_pt(), accuracy_pt(s.correct, s.answered)).into())
+        .collect();
+
+    DrillQuestion {
+        prompt: format!(
+            "{} atacando {}: qual a efetividade?",
+            type_label_pt(matchup.attack),
+            matchup.defenders_pt()
+        )
+        .into(),
+        attack: tag(matchup.attack),
+        defenders: ModelRc::new(VecModel::from(defenders)),
+        choices: ModelRc::new(VecModel::from(choices)),
+        chosen: chosen.map_or(-1, |c| c as i32),
+        answer: if chosen.is_some() { answer as i32 } else { -1 },
+        feedback: feedback.into(),
+        record: record.into(),
+        summary: format!(
+            "Total: {} · Para revisar: {}",
+            accuracy_pt(correct, answered),
+            stats.to_review()
+        )
+        .into(),
+        hardest: ModelRc::new(VecModel::from(hardest)),
+    }
+}
+
+fn set_detail_error(app: &App, msg: &str) {
+    app.set_detail(PokemonDetail {
+        name: "".into(),
+        id: 0,
+        height: 0,
+        weight: 0,
+        heightText: "".into(),
+        weightText: "".into(),
+        types: ModelRc::new(VecModel::from(Vec::<TypeTag>::new())),
+        stats: ModelRc::new(VecModel::from(Vec::<StatBar>::new())),
+        artwork: slint::Image::default(),
+        frames: ModelRc::default(),
+        frameDelays: ModelRc::default(),
+        total: 0,
+        ability1: "".into(),
+        ability2: "".into(),
+        hiddenAbility: "".into(),
+        error: msg.into(),
+        color: Brush::from(Color::from_argb_encoded(0x00000000)),
+        genderDifferences: false,
+        generation: "".into(),
+        announcement: "".into(),
+    });
+}
+
+fn set_detail_empty(app: &App) {
+    app.set_detail(PokemonDetail {
+        name: "Carregando...".into(),
+        id: 0,
+        height: 0,
+        weight: 0,
+        heightText: "".into(),
+        weightText: "".into(),
+        types: ModelRc::new(VecModel::from(Vec::<TypeTag>::new())),
+        stats: ModelRc::new(VecModel::from(Vec::<StatBar>::new())),
+        artwork: slint::Image::default(),
+        frames: ModelRc::default(),
+        frameDelays: ModelRc::default(),
+        total: 0,
+        ability1: "".into(),
+        ability2: "".into(),
+        hiddenAbility: "".into(),
+        error: "".into(),
+        color: Brush::from(Color::from_argb_encoded(0x00000000)),
+        genderDifferences: false,
+        generation: "".into(),
+        announcement: "Carregando detalhes".into(),
+    });
+}
+
+fn set_team_rows(app: &App, team: &[showdown::TeamMember]) {
+    let rows: Vec<TeamMemberRow> = team
+        .iter()
+        .map(|member| {
+            let pokemon = POKEMON_LIST.iter().find(|p| p.id == member.species_id);
+            let title = match &member.nickname {
+                Some(nick) => format!("{nick} ({})", member.species),
+                None => member.species.clone(),
+            };
+            let info: Vec<String> = [
+                member.item.clone(),
+                member.ability.clone(),
+                member.nature.clone(),
+                member.tera_type.as_ref().map(|t| format!("Tera {t}")),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+            TeamMemberRow {
+                title: title.into(),
+                species_id: member.species_id as i32,
+                info: info.join(" · ").into(),
+                evs: showdown::format_spread(&member.evs, 0)
+                    .map(|evs| format!("EVs: {evs}"))
+                    .unwrap_or_default()
+                    .into(),
+                moves: member.moves.join(" / ").into(),
+                color: pokemon.map_or(NO_COLOR.into(), |p| pokemon_color(p.color)),
+                tera_icon: member
+                    .tera_type
+                    .as_deref()
+                    .and_then(|tera| {
+                        PokemonType::ALL
+                            .into_iter()
+                            .find(|t| t.id().eq_ignore_ascii_case(tera))
+                    })
+                    .map(type_icon)
+                    .unwrap_or_default(),
+            }
+        })
+        .collect();
+    app.set_team(ModelRc::new(VecModel::from(rows)));
+}
+
+// =================== View Slint ===================
+impl View for slint::Weak<App> {
+    fn hide_splash(&self) {
+        if let Some(app) = self.upgrade() {
+            app.set_splash(false);
+        }
+    }
+
+    fn set_rows(&self, rows: &[Pokemon]) {
+        if let Some(app) = self.upgrade() {
+            let palette = palette_choice(app.get_palette_mode());
+            set_rows_from_pokemon(&app, rows, palette);
+        }
+    }
+
+    fn set_thumb(&self, id: u32, thumb: &Artwork) {
+        if let Some(app) = self.upgrade() {
+            let rows = app.get_rows();
+            if let Some(i) = rows.iter().position(|row| row.id == id as i32) {
+                let mut row = rows.row_data(i).unwrap();
+                row.thumb = thumb.to_image();
+                rows.set_row_data(i, row);
+            }
+        }
+    }
+
+    fn set_text_rows(&self, on: bool) {
+        if let Some(app) = self.upgrade() {
+            app.set_text_rows(on);
+        }
+    }
+
+    fn set_grid_view(&self, on: bool) {
+        if let Some(app) = self.upgrade() {
+            app.set_grid_view(on);
+        }
+    }
+
+    fn set_language(&self, language: Language) {
+        if let Some(app) = self.upgrade() {
+            app.set_language_mode(match language {
+                Language::PtBr => LanguageMode::PtBr,
+                Language::EnUs => LanguageMode::EnUs,
+            });
+        }
+    }
+
+    fn set_units(&self, units: Units) {
+        if let Some(app) = self.upgrade() {
+            app.set_units_mode(match units {
+                Units::Metric => UnitsMode::Metric,
+                Units::Imperial => UnitsMode::Imperial,
+                Units::Both => UnitsMode::Both,
+            });
+        }
+    }
+
+    fn set_filter(&self, text: &str) {
+        if let Some(app) = self.upgrade() {
+            app.set_filter(SharedString::from(text));
+        }
+    }
+
+    fn set_selected_index(&self, idx: i32) {
+        if let Some(app) = self.upgrade() {
+            app.set_selected_index(idx);
+            // sem seleção, o compacto volta para a lista
+            if idx < 0 {
+                app.set_visualiza_pokemon(false);
+            }
+        }
+    }
+
+    fn show_loading(&self, idx: i32) {
+        if let Some(app) = self.upgrade() {
+            app.set_carregando(true);
+            set_detail_empty(&app);
+            app.set_visualiza_pokemon(true);
+            app.set_selected_index(idx);
+        }
+    }
+
+    fn show_detail(&self, detail: &service::Detail, artwork: Option<&Artwork>) {
+        if let Some(app) = self.upgrade() {
+            let palette = palette_choice(app.get_palette_mode());
+            let units = units_choice(app.get_units_mode());
+            let language = language_choice(app.get_language_mode());
+            app.set_detail(make_detail_for_ui(
+                detail, artwork, palette, units, language,
+            ));
+            app.set_carregando(false);
+        }
+    }
+
+    fn show_size(&self, reference: Option<&SizeReference>) {
+        if let Some(app) = self.upgrade() {
+            let units = units_choice(app.get_units_mode());
+            let language = language_choice(app.get_language_mode());
+            app.set_size_comparison(make_size_for_ui(
+                &app.get_detail(),
+                reference,
+                units,
+                language,
+            ));
+        }
+    }
+
+    fn show_size_error(&self, msg: &str) {
+        if let Some(app) = self.upgrade() {
+            let mut comparison = app.get_size_comparison();
+            comparison.error = msg.into();
+            app.set_size_comparison(comparison);
+        }
+    }
+
+    fn artwork_size(&self) -> u32 {
+        let scale = self
+            .upgrade()
+            .map(|app| app.window().scale_factor())
+            .unwrap_or(1.0);
+        (ARTWORK_SIZE * scale).ceil() as u32
+    }
+
+    fn show_error(&self, msg: &str) {
+        if let Some(app) = self.upgrade() {
+            set_detail_error(&app, msg);
+            app.set_carregando(false);
+        }
+    }
+
+    fn set_team(&self, team: &[showdown::TeamMember]) {
+        if let Some(app) = self.upgrade() {
+            set_team_rows(&app, team);
+        }
+    }
+
+    fn set_team_text(&self, text: &str) {
+        if let Some(app) = self.upgrade() {
+            app.set_team_text(text.into());
+        }
+    }
+
+    fn set_team_error(&self, msg: &str) {
+        if let Some(app) = self.upgrade() {
+            app.set_team_error(msg.into());
+        }
+    }
+
+    fn show_page(&self, page: Page) {
+        if let Some(app) = self.upgrade() {
+            app.set_visualiza_time(page == Page::Team);
+            app.set_visualiza_quiz(page == Page::Quiz);
+            app.set_visualiza_treino(page == Page::Drill);
+            app.set_visualiza_config(page == Page::Settings);
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn set_route(&self, _route: &Route, _replace: bool) {}
+
+    #[cfg(target_arch = "wasm32")]
+    fn set_route(&self, route: &Route, replace: bool) {
+        let Some(history) = web_sys::window().and_then(|w| w.history().ok()) else {
+            return;
+        };
+        let url = format!("#{}", route.to_hash());
+        let result = if replace {
+            history.replace_state_with_url(&JsValue::NULL, "", Some(&url))
+        } else {
+            history.push_state_with_url(&JsValue::NULL, "", Some(&url))
+        };
+        if let Err(e) = result {
+            web_sys::console::warn_1(&e);
+        }
+    }
+
+    fn set_theme(&self, theme: ThemeChoice) {
+        if let Some(app) = self.upgrade() {
+            app.set_theme_mode(match theme {
+                ThemeChoice::System => ThemeMode::System,
+                ThemeChoice::Light => ThemeMode::Light,
+                ThemeChoice::Dark => ThemeMode::Dark,
+                ThemeChoice::HighContrast => ThemeMode::HighContrast,
+            });
+        }
+    }
+
+    fn set_palette(&self, palette: PaletteChoice) {
+        if let Some(app) = self.upgrade() {
+            app.set_palette_mode(match palette {
+                PaletteChoice::Default => PaletteMode::Default,
+                PaletteChoice::Deuteranopia => PaletteMode::Deuteranopia,
+                PaletteChoice::Protanopia => PaletteMode::Protanopia,
+                PaletteChoice::Tritanopia => PaletteMode::Tritanopia,
+            });
+            // chips das linhas mudam de cor; as miniaturas ficam
+            let rows = app.get_rows();
+            for i in 0..rows.row_count() {
+                let mut row = rows.row_data(i).unwrap();
+                let types = POKEMON_LIST
+                    .iter()
+                    .find(|p| p.id as i32 == row.id)
+                    .map_or(&[][..], |p| p.types);
+                row.types = type_tags(types, palette);
+                rows.set_row_data(i, row);
+            }
+        }
+    }
+
+    fn set_cache_limits(&self, limits: CacheLimits) {
+        if let Some(app) = self.upgrade() {
+            app.set_cache_details(limits.details as i32);
+            app.set_cache_artwork_mb(limits.artwork_mb as i32);
+        }
+    }
+
+    fn show_quiz_image(&self, image: Option<&Artwork>) {
+        if let Some(app) = self.upgrade() {
+            app.set_quiz_image(image.map(Artwork::to_image).unwrap_or_default());
+        }
+    }
+
+    fn set_quiz_suggestions(&self, suggestions: &[Pokemon]) {
+        if let Some(app) = self.upgrade() {
+            let names: Vec<SharedString> = suggestions.iter().map(|p| p.name.into()).collect();
+            app.set_quiz_suggestions(ModelRc::new(VecModel::from(names)));
+        }
+    }
+
+    fn set_quiz_status(&self, revealed: bool, feedback: &str, score: &QuizScore) {
+        if let Some(app) = self.upgrade() {
+            app.set_quiz_revealed(revealed);
+            app.set_quiz_feedback(feedback.into());
+            app.set_quiz_stats(QuizStats {
+                streak: score.streak as i32,
+                best: score.best_streak as i32,
+                correct: score.correct as i32,
+                answered: score.answered as i32,
+            });
+        }
+    }
+
+    fn show_drill(&self, matchup: &Matchup, chosen: Option<usize>, stats: &DrillStats) {
+        if let Some(app) = self.upgrade() {
+            app.set_drill(make_drill_for_ui(
+                matchup,
+                chosen,
+                stats,
+                palette_choice(app.get_palette_mode()),
+            ));
+        }
+    }
+}
+
+fn palette_choice(mode: PaletteMode) -> PaletteChoice {
+    match mode {
+        PaletteMode::Default => PaletteChoice::Default,
+        PaletteMode::Deuteranopia => PaletteChoice::Deuteranopia,
+        PaletteMode::Protanopia => PaletteChoice::Protanopia,
+        PaletteMode::Tritanopia => PaletteChoice::Tritanopia,
+    }
+}
+
+fn language_choice(mode: LanguageMode) -> Language {
+    match mode {
+        LanguageMode::PtBr => Language::PtBr,
+        LanguageMode::EnUs => Language::EnUs,
+    }
+}
+
+fn units_choice(mode: UnitsMode) -> Units {
+    match mode {
+        UnitsMode::Metric => Units::Metric,
+        UnitsMode::Imperial => Units::Imperial,
+        UnitsMode::Both => Units::Both,
+    }
+}
+
+// =================== Ligação dos callbacks ===================
+/// Controller da janela: PokeAPI e ajustes guardados na plataforma
+type AppController<E> = Controller<slint::Weak<App>, E, Pokedex, PlatformSettings>;
+
+fn wire_app<E: Executor>(app: &App, exec: E, route: Route) -> AppController<E> {
+    let ctrl = Controller::new(app.as_weak(), exec, Pokedex::new(), PlatformSettings);
+
+    let c = ctrl.clone();
+    app.on_request_load(move || c.request_load());
+
+    let c = ctrl.clone();
+    app.on_select(move |idx| c.select(idx));
+
+    let c = ctrl.clone();
+    app.on_generation_start(move |generation| {
+        u8::try_from(generation).map_or(-1, |g| c.generation_start(g))
+    });
+
+    let c = ctrl.clone();
+    app.on_find_prefix(move |prefix, from| c.find_prefix(prefix.as_str(), from));
+
+    let c = ctrl.clone();
+    app.on_apply_filter(move |f: SharedString| c.apply_filter(f.as_str()));
+
+    let c = ctrl.clone();
+    app.on_filter_edited(move |f: SharedString| c.filter_edited(f.as_str()));
+
+    let c = ctrl.clone();
+    app.on_variant_changed(move |shiny, female, animated| {
+        c.set_variant(service::SpriteVariant {
+            shiny,
+            female,
+            animated,
+        })
+    });
+
+    let c = ctrl.clone();
+    app.on_import_team(move |text: SharedString| c.import_team(text.as_str()));
+
+    let c = ctrl.clone();
+    app.on_export_team(move || c.export_team());
+
+    let c = ctrl.clone();
+    app.on_open_team_member(move |species_id| {
+        if let Ok(id) = u32::try_from(species_id) {
+            c.open_team_member(id);
+        }
+    });
+
+    let c = ctrl.clone();
+    app.on_theme_changed(move |mode| {
+        c.set_theme(match mode {
+            ThemeMode::System => ThemeChoice::System,
+            ThemeMode::Light => ThemeChoice::Light,
+            ThemeMode::Dark => ThemeChoice::Dark,
+            ThemeMode::HighContrast => ThemeChoice::HighContrast,
+        })
+    });
+
+    let c = ctrl.clone();
+    app.on_palette_changed(move |mode| c.set_palette(palette_choice(mode)));
+
+    let c = ctrl.clone();
+    app.on_text_rows_changed(move |on| c.set_text_rows(on));
+
+    let c = ctrl.clone();
+    app.on_grid_view_changed(move |on| c.set_grid_view(on));
+
+    let c = ctrl.clone();
+    app.on_size_reference_changed(move |query| c.set_size_reference(&query));
+
+    let c = ctrl.clone();
+    app.on_units_changed(move |mode| c.set_units(units_choice(mode)));
+
+    let c = ctrl.clone();
+    app.on_language_changed(move |mode| c.set_language(language_choice(mode)));
+
+    let c = ctrl.clone();
+    app.on_request_thumb(move |id| {
+        if let Ok(id) = u32::try_from(id) {
+            c.request_thumb(id);
+        }
+    });
+
+    let c = ctrl.clone();
+    app.on_cache_changed(move |details, artwork_mb| {
+        c.set_cache_limits(CacheLimits {
+            details: usize::try_from(details).unwrap_or_default(),
+            artwork_mb: usize::try_from(artwork_mb).unwrap_or_default(),
+        })
+    });
+
+    // opções do quiz: "todas" + uma por geração
+    let quiz_generations: Vec<SharedString> = std::iter::once("Todas as gerações".into())
+        .chain((1..=REGIONS.len() as u8).map(|g| generation_label(g).into()))
+        .collect();
+    app.set_quiz_generations(ModelRc::new(VecModel::from(quiz_generations)));
+
+    let c = ctrl.clone();
+    app.on_quiz_next(move || c.quiz_next());
+
+    let c = ctrl.clone();
+    app.on_drill_next(move || c.drill_next());
+
+    let c = ctrl.clone();
+    app.on_drill_answer(move |choice| {
+        if let Ok(choice) = usize::try_from(choice) {
+            c.drill_answer(choice);
+        }
+    });
+
+    let c = ctrl.clone();
+    app.on_quiz_edited(move |text: SharedString| c.quiz_edited(text.as_str()));
+
+    let c = ctrl.clone();
+    app.on_quiz_guess(move |text: SharedString| c.quiz_guess(text.as_str()));
+
+    let c = ctrl.clone();
+    app.on_quiz_give_up(move || c.quiz_give_up());
+
+    let c = ctrl.clone();
+    app.on_quiz_generation_changed(move |generation| {
+        if let Ok(g) = u8::try_from(generation) {
+            c.set_quiz_generation(g);
+        }
+    });
+
+    let c = ctrl.clone();
+    let weak = app.as_weak();
+    app.on_page_changed(move || {
+        let Some(app) = weak.upgrade() else {
+            return;
+        };
+        c.set_page(if app.get_visualiza_time() {
+            Page::Team
+        } else if app.get_visualiza_quiz() {
+            Page::Quiz
+        } else if app.get_visualiza_treino() {
+            Page::Drill
+        } else if app.get_visualiza_config() {
+            Page::Settings
+        } else {
+            Page::Pokedex
+        });
+    });
+
+    #[cfg(not(any(target_arch = "wasm32", target_os = "android")))]
+    restore_window(app, &ctrl);
+
+    ctrl.start(route);
+    ctrl
+}
+
+/// Reabre a janela onde estava e guarda a geometria ao fechar
+#[cfg(not(any(target_arch = "wasm32", target_os = "android")))]
+fn restore_window<E: Executor>(app: &App, ctrl: &AppController<E>) {
+    let window = app.window();
+    if let Some(geometry) = ctrl.window().filter(|g| g.width > 0 && g.height > 0) {
+        window.set_position(slint::PhysicalPosition::new(geometry.x, geometry.y));
+        window.set_size(slint::PhysicalSize::new(geometry.width, geometry.height));
+        window.set_maximized(geometry.maximized);
+    }
+
+    let c = ctrl.clone();
+    let weak = app.as_weak();
+    window.on_close_requested(move || {
+        if let Some(app) = weak.upgrade() {
+            let window = app.window();
+            let (position, size) = (window.position(), window.size());
+            c.set_window(settings::WindowGeometry {
+                x: position.x,
+                y: position.y,
+                width: size.width,
+                height: size.height,
+                maximized: window.is_maximized(),
+            });
+        }
+        slint::CloseRequestResponse::HideWindow
+    });
+}
+
+// =================== Desktop ===================
+#[cfg(not(target_arch = "wasm32"))]
+pub fn start_desktop() -> Result<(), slint::PlatformError> {
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let route = Route::from_args(&args).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        None
+    });
+    let app = App::new()?;
+    wire_app(
+        &app,
+        executor::TokioExecutor::new(rt.handle().clone()),
+        route.unwrap_or_default(),
+    );
+    app.run()
+}
+
+// =================== WebAssembly ===================
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen(start)]
+pub fn start_wasm() {
+    console_error_panic_hook::set_once();
+    let app = App::new().expect("create app");
+    let ctrl = wire_app(&app, executor::WasmExecutor, current_hash_route());
+
+    // voltar/avançar do navegador e hash editado à mão
+    let on_popstate = Closure::<dyn FnMut()>::new(move || ctrl.open_route(current_hash_route()));
+    if let Some(window) = web_sys::window() {
+        window
+            .add_event_listener_with_callback("popstate", on_popstate.as_ref().unchecked_ref())
+            .ok();
+    }
+    on_popstate.forget();
+
+    app.run().expect("run app");
+}
+
+/// Rota do hash da URL; hash vazio ou desconhecido abre a lista
+#[cfg(target_arch = "wasm32")]
+fn current_hash_route() -> Route {
+    web_sys::window()
+        .and_then(|w| w.location().hash().ok())
+        .and_then(|hash| Route::parse(&hash))
+        .unwrap_or_default()
+}
+
+// =================== Android ===================
+#[cfg(target_os = "android")]
+#[unsafe(no_mangle)]
+fn android_main(app: slint::android::AndroidApp) {
+    if let Some(dir) = app.internal_data_path() {
+        settings::set_data_dir(dir);
+    }
+    slint::android::init(app).expect("falha ao inicializar Slint no Android");
+    if let Err(e) = start_desktop() {
+        eprintln!("erro ao iniciar app: {e}");
+    }
+}
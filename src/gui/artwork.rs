@@ -0,0 +1,433 @@
+use image::{AnimationDecoder, ImageFormat, RgbaImage};
+use lru::LruCache;
+use slint::{Rgba8Pixel, SharedPixelBuffer};
+use std::{hash::Hash, io::Cursor, ops::Range, sync::Arc};
+
+/// Pixels já decodificados e no tamanho de exibição (prontos para `Image::from_rgba8`)
+pub type Pixels = SharedPixelBuffer<Rgba8Pixel>;
+
+/// Quadro de uma sprite animada
+#[derive(Clone)]
+pub struct Frame {
+    pub pixels: Pixels,
+    pub delay_ms: u32,
+}
+
+/// Sprite pronta para a UI, em qualquer formato suportado
+#[derive(Clone)]
+pub enum Artwork {
+    /// PNG/JPEG (ou GIF/WebP de um quadro só)
+    Still(Pixels),
+    /// GIF/WebP animados
+    Animated(Vec<Frame>),
+    /// SVG cru; o Slint rasteriza no tamanho exibido
+    Svg(Arc<[u8]>),
+}
+
+impl Artwork {
+    /// Imagem estática: a própria, ou o primeiro quadro da animação.
+    /// Precisa rodar na thread da UI (`slint::Image` não é `Send`).
+    pub fn to_image(&self) -> slint::Image {
+        match self {
+            Artwork::Still(pixels) => slint::Image::from_rgba8(pixels.clone()),
+            Artwork::Animated(frames) => frames
+                .first()
+                .map(|f| slint::Image::from_rgba8(f.pixels.clone()))
+                .unwrap_or_default(),
+            Artwork::Svg(data) => slint::Image::load_from_svg_data(data).unwrap_or_default(),
+        }
+    }
+
+    /// Quadros e tempos (ms) da animação; vazio para imagens estáticas
+    pub fn frames(&self) -> Vec<(slint::Image, i32)> {
+        match self {
+            Artwork::Animated(frames) => frames
+                .iter()
+                .map(|f| {
+                    (
+                        slint::Image::from_rgba8(f.pixels.clone()),
+                        f.delay_ms as i32,
+                    )
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Silhueta preta (só o alfa da imagem) para o quiz; SVG não tem pixels para isso
+    pub fn silhouette(&self) -> Option<Artwork> {
+        let pixels = match self {
+            Artwork::Still(pixels) => pixels,
+            Artwork::Animated(frames) => &frames.first()?.pixels,
+            Artwork::Svg(_) => return None,
+        };
+        let mut img =
+            RgbaImage::from_raw(pixels.width(), pixels.height(), pixels.as_bytes().to_vec())?;
+        for p in img.pixels_mut() {
+            p.0 = [0, 0, 0, p.0[3]];
+        }
+        Some(Artwork::Still(Pixels::clone_from_slice(
+            img.as_raw(),
+            img.width(),
+            img.height(),
+        )))
+    }
+
+    fn byte_size(&self) -> usize {
+        match self {
+            Artwork::Still(pixels) => pixels.as_bytes().len(),
+            Artwork::Animated(frames) => frames.iter().map(|f| f.pixels.as_bytes().len()).sum(),
+            Artwork::Svg(data) => data.len(),
+        }
+    }
+}
+
+/// Orçamento padrão do cache de artworks decodificadas
+pub const DEFAULT_BUDGET_BYTES: usize = 24 * 1024 * 1024;
+
+/// Linhas reduzidas por vez no wasm antes de devolver o controle à UI
+#[cfg(target_arch = "wasm32")]
+const ROWS_PER_CHUNK: u32 = 32;
+
+/// Quadros mais rápidos que isso usam o padrão dos navegadores (100 ms)
+const MIN_FRAME_MS: u32 = 20;
+const DEFAULT_FRAME_MS: u32 = 100;
+
+/// LRU limitado pela memória ocupada pelos pixels, não pela quantidade
+pub struct ArtworkCache<K: Hash + Eq> {
+    entries: LruCache<K, Artwork>,
+    used: usize,
+    budget: usize,
+}
+
+impl<K: Hash + Eq> ArtworkCache<K> {
+    pub fn new(budget: usize) -> Self {
+        Self {
+            entries: LruCache::unbounded(),
+            used: 0,
+            budget,
+        }
+    }
+
+    pub fn get(&mut self, key: &K) -> Option<Artwork> {
+        self.entries.get(key).cloned()
+    }
+
+    pub fn put(&mut self, key: K, artwork: Artwork) {
+        let size = artwork.byte_size();
+        if size > self.budget {
+            return;
+        }
+        if let Some(old) = self.entries.put(key, artwork) {
+            self.used -= old.byte_size();
+        }
+        self.used += size;
+        self.evict();
+    }
+
+    /// Muda o orçamento, descartando as menos usadas se passar
+    pub fn set_budget(&mut self, budget: usize) {
+        self.budget = budget;
+        self.evict();
+    }
+
+    fn evict(&mut self) {
+        while self.used > self.budget {
+            match self.entries.pop_lru() {
+                Some((_, old)) => self.used -= old.byte_size(),
+                None => break,
+            }
+        }
+    }
+}
+
+/// Tamanho final mantendo a proporção; nunca amplia
+fn target_size(w: u32, h: u32, max_side: u32) -> (u32, u32) {
+    let longest = w.max(h);
+    if longest <= max_side || max_side == 0 {
+        return (w, h);
+    }
+    let scale = max_side as f64 / longest as f64;
+    (
+        ((w as f64 * scale).round() as u32).max(1),
+        ((h as f64 * scale).round() as u32).max(1),
+    )
+}
+
+/// Reduz as linhas `rows` do destino tirando a média da área correspondente da origem
+fn scale_rows(src: &RgbaImage, dst: &mut Pixels, rows: Range<u32>) {
+    let (sw, sh) = src.dimensions();
+    let (dw, dh) = (dst.width(), dst.height());
+    let dst_width = dw as usize;
+    let pixels = dst.make_mut_slice();
+    for y in rows {
+        let y0 = (y as u64 * sh as u64 / dh as u64) as u32;
+        let y1 = (((y + 1) as u64 * sh as u64).div_ceil(dh as u64) as u32).max(y0 + 1);
+        for x in 0..dw {
+            let x0 = (x as u64 * sw as u64 / dw as u64) as u32;
+            let x1 = (((x + 1) as u64 * sw as u64).div_ceil(dw as u64) as u32).max(x0 + 1);
+            let mut acc = [0u32; 4];
+            for sy in y0..y1 {
+                for sx in x0..x1 {
+                    let p = src.get_pixel(sx, sy).0;
+                    for c in 0..4 {
+                        acc[c] += p[c] as u32;
+                    }
+                }
+            }
+            let n = (y1 - y0) * (x1 - x0);
+            pixels[y as usize * dst_width + x as usize] = Rgba8Pixel::new(
+                (acc[0] / n) as u8,
+                (acc[1] / n) as u8,
+                (acc[2] / n) as u8,
+                (acc[3] / n) as u8,
+            );
+        }
+    }
+}
+
+/// Resultado da decodificação, antes de reduzir
+enum Decoded {
+    Svg,
+    Frames(Vec<(RgbaImage, u32)>),
+}
+
+fn is_svg(bytes: &[u8]) -> bool {
+    let head = &bytes[..bytes.len().min(512)];
+    let text = String::from_utf8_lossy(head);
+    let text = text.trim_start_matches('\u{feff}').trim_start();
+    text.starts_with("<svg") || (text.starts_with("<?xml") && text.contains("<svg"))
+}
+
+fn collect_frames<'a>(decoder: impl AnimationDecoder<'a>) -> Result<Vec<(RgbaImage, u32)>, String> {
+    decoder
+        .into_frames()
+        .map(|frame| {
+            let frame = frame.map_err(|e| e.to_string())?;
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let ms = numer / denom.max(1);
+            let ms = if ms < MIN_FRAME_MS {
+                DEFAULT_FRAME_MS
+            } else {
+                ms
+            };
+            Ok((frame.into_buffer(), ms))
+        })
+        .collect()
+}
+
+/// Detecta o formato e decodifica todos os quadros
+fn decode(bytes: &[u8]) -> Result<Decoded, String> {
+    if is_svg(bytes) {
+        return Ok(Decoded::Svg);
+    }
+    let frames = match image::guess_format(bytes).map_err(|e| e.to_string())? {
+        ImageFormat::Gif => {
+            let decoder = image::codecs::gif::GifDecoder::new(Cursor::new(bytes))
+                .map_err(|e| e.to_string())?;
+            collect_frames(decoder)?
+        }
+        ImageFormat::WebP => {
+            let decoder = image::codecs::webp::WebPDecoder::new(Cursor::new(bytes))
+                .map_err(|e| e.to_string())?;
+            if decoder.has_animation() {
+                collect_frames(decoder)?
+            } else {
+                vec![(still(bytes)?, 0)]
+            }
+        }
+        _ => vec![(still(bytes)?, 0)],
+    };
+    Ok(Decoded::Frames(frames))
+}
+
+fn still(bytes: &[u8]) -> Result<RgbaImage, String> {
+    image::load_from_memory(bytes)
+        .map(|img| img.to_rgba8())
+        .map_err(|e| e.to_string())
+}
+
+fn into_artwork(frames: Vec<(Pixels, u32)>) -> Artwork {
+    match <[_; 1]>::try_from(frames) {
+        Ok([(pixels, _)]) => Artwork::Still(pixels),
+        Err(frames) => Artwork::Animated(
+            frames
+                .into_iter()
+                .map(|(pixels, delay_ms)| Frame { pixels, delay_ms })
+                .collect(),
+        ),
+    }
+}
+
+/// Decodifica e reduz para caber em `max_side` pixels (bloqueante; rodar fora da UI).
+/// `max_side` 0 mantém o tamanho original.
+pub fn decode_scaled(bytes: &[u8], max_side: u32) -> Result<Artwork, String> {
+    let Decoded::Frames(frames) = decode(bytes)? else {
+        return Ok(Artwork::Svg(bytes.into()));
+    };
+    let scaled = frames
+        .into_iter()
+        .map(|(src, delay)| {
+            let (w, h) = target_size(src.width(), src.height(), max_side);
+            if (w, h) == src.dimensions() {
+                return (Pixels::clone_from_slice(src.as_raw(), w, h), delay);
+            }
+            let mut dst = Pixels::new(w, h);
+            scale_rows(&src, &mut dst, 0..h);
+            (dst, delay)
+        })
+        .collect();
+    Ok(into_artwork(scaled))
+}
+
+/// Igual a `decode_scaled`, mas devolve o controle à UI entre blocos de linhas
+#[cfg(target_arch = "wasm32")]
+pub async fn decode_scaled_chunked(bytes: Vec<u8>, max_side: u32) -> Result<Artwork, String> {
+    yield_to_ui().await;
+    let Decoded::Frames(frames) = decode(&bytes)? else {
+        return Ok(Artwork::Svg(bytes.into()));
+    };
+    let mut scaled = Vec::with_capacity(frames.len());
+    for (src, delay) in frames {
+        let (w, h) = target_size(src.width(), src.height(), max_side);
+        if (w, h) == src.dimensions() {
+            scaled.push((Pixels::clone_from_slice(src.as_raw(), w, h), delay));
+            continue;
+        }
+        let mut dst = Pixels::new(w, h);
+        let mut row = 0;
+        while row < h {
+            yield_to_ui().await;
+            let end = (row + ROWS_PER_CHUNK).min(h);
+            scale_rows(&src, &mut dst, row..end);
+            row = end;
+        }
+        scaled.push((dst, delay));
+    }
+    Ok(into_artwork(scaled))
+}
+
+/// Future que termina no próximo giro do event loop do Slint
+#[cfg(target_arch = "wasm32")]
+fn yield_to_ui() -> impl std::future::Future<Output = ()> {
+    use std::{cell::RefCell, rc::Rc, task::Poll, task::Waker};
+
+    let shared: Rc<RefCell<(bool, Option<Waker>)>> = Rc::default();
+    let timer_shared = shared.clone();
+    slint::Timer::single_shot(std::time::Duration::ZERO, move || {
+        let mut s = timer_shared.borrow_mut();
+        s.0 = true;
+        if let Some(waker) = s.1.take() {
+            waker.wake();
+        }
+    });
+    std::future::poll_fn(move |cx| {
+        let mut s = shared.borrow_mut();
+        if s.0 {
+            Poll::Ready(())
+        } else {
+            s.1 = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Artwork estática de `w`×`h` (4 bytes por pixel)
+    fn blank(w: u32, h: u32) -> Artwork {
+        Artwork::Still(Pixels::new(w, h))
+    }
+
+    fn png(w: u32, h: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        RgbaImage::new(w, h)
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    fn dimensions(artwork: &Artwork) -> (u32, u32) {
+        match artwork {
+            Artwork::Still(pixels) => (pixels.width(), pixels.height()),
+            _ => panic!("esperava imagem estática"),
+        }
+    }
+
+    #[test]
+    fn put_evicts_least_recently_used() {
+        // cada uma ocupa 10×10×4 = 400 bytes; cabem duas
+        let mut cache = ArtworkCache::new(800);
+        cache.put(1, blank(10, 10));
+        cache.put(2, blank(10, 10));
+        cache.get(&1);
+        cache.put(3, blank(10, 10));
+        assert!(cache.get(&1).is_some());
+        assert!(cache.get(&2).is_none());
+        assert!(cache.get(&3).is_some());
+        assert_eq!(cache.used, 800);
+    }
+
+    #[test]
+    fn put_skips_artwork_over_budget() {
+        let mut cache = ArtworkCache::new(800);
+        cache.put(1, blank(10, 10));
+        cache.put(2, blank(20, 20));
+        assert!(cache.get(&1).is_some());
+        assert!(cache.get(&2).is_none());
+        assert_eq!(cache.used, 400);
+    }
+
+    #[test]
+    fn replacing_a_key_keeps_the_count_right() {
+        let mut cache = ArtworkCache::new(800);
+        cache.put(1, blank(10, 10));
+        cache.put(1, blank(5, 5));
+        assert_eq!(cache.used, 100);
+    }
+
+    #[test]
+    fn set_budget_evicts_down_to_the_new_limit() {
+        let mut cache = ArtworkCache::new(1200);
+        for id in 1..=3 {
+            cache.put(id, blank(10, 10));
+        }
+        cache.set_budget(400);
+        assert!(cache.get(&1).is_none());
+        assert!(cache.get(&2).is_none());
+        assert!(cache.get(&3).is_some());
+        assert_eq!(cache.used, 400);
+
+        cache.set_budget(0);
+        assert!(cache.get(&3).is_none());
+        assert_eq!(cache.used, 0);
+    }
+
+    #[test]
+    fn decode_scaled_keeps_proportion() {
+        let artwork = decode_scaled(&png(200, 100), 50).unwrap();
+        assert_eq!(dimensions(&artwork), (50, 25));
+        let artwork = decode_scaled(&png(30, 120), 60).unwrap();
+        assert_eq!(dimensions(&artwork), (15, 60));
+    }
+
+    #[test]
+    fn decode_scaled_never_enlarges() {
+        assert_eq!(
+            dimensions(&decode_scaled(&png(40, 20), 100).unwrap()),
+            (40, 20)
+        );
+        assert_eq!(
+            dimensions(&decode_scaled(&png(40, 20), 0).unwrap()),
+            (40, 20)
+        );
+    }
+
+    #[test]
+    fn decode_scaled_passes_svg_through() {
+        let svg = b"<svg xmlns=\"http://www.w3.org/2000/svg\"/>";
+        assert!(matches!(decode_scaled(svg, 50), Ok(Artwork::Svg(_))));
+    }
+}
@@ -0,0 +1,1291 @@
+#[cfg(not(any(target_arch = "wasm32", target_os = "android")))]
+use super::settings::WindowGeometry;
+use super::{
+    artwork::{Artwork, ArtworkCache},
+    drill::{self, DrillStats, Matchup},
+    executor::Executor,
+    quiz::{self, QuizScore, Round},
+    route::{Page, Route},
+    settings::{CacheLimits, PaletteChoice, Session, Settings, SettingsStore, ThemeChoice},
+    size::SizeReference,
+    source::DataSource,
+    thumbs::{THUMB_SIZE, Thumbs},
+};
+use crate::{
+    POKEMON_LIST, Pokemon, find_pokemon,
+    helpers::{Language, Units},
+    search_pokemon,
+    service::{Detail, SpriteVariant},
+    showdown,
+};
+use std::{
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+const SPLASH: Duration = Duration::from_secs(2);
+/// Espera após a última tecla antes de filtrar
+const FILTER_DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// O que o controller precisa da tela. Implementado pela janela Slint,
+/// mas qualquer tipo serve (ex.: um mock que só registra as chamadas).
+pub trait View: Clone + Send + 'static {
+    fn hide_splash(&self);
+    fn set_rows(&self, rows: &[Pokemon]);
+    fn set_thumb(&self, id: u32, thumb: &Artwork);
+    fn set_text_rows(&self, on: bool);
+    fn set_grid_view(&self, on: bool);
+    fn set_units(&self, units: Units);
+    /// Idioma dos números formatados nos próximos `show_detail`
+    fn set_language(&self, language: Language);
+    fn set_filter(&self, text: &str);
+    fn set_selected_index(&self, idx: i32);
+    /// Limpa o detalhe e mostra "Carregando..." para o índice `idx`
+    fn show_loading(&self, idx: i32);
+    fn show_detail(&self, detail: &Detail, artwork: Option<&Artwork>);
+    /// Compara o detalhe mostrado com a pessoa (`None`) ou outro Pokémon
+    fn show_size(&self, reference: Option<&SizeReference>);
+    fn show_size_error(&self, msg: &str);
+    /// Lado maior da artwork na tela, em pixels físicos
+    fn artwork_size(&self) -> u32;
+    fn show_error(&self, msg: &str);
+    fn set_team(&self, team: &[showdown::TeamMember]);
+    fn set_team_text(&self, text: &str);
+    fn set_team_error(&self, msg: &str);
+    fn show_page(&self, page: Page);
+    /// Rota atual mudou (na web vira o hash da URL); `replace` não cria entrada no histórico
+    fn set_route(&self, route: &Route, replace: bool);
+    fn set_theme(&self, theme: ThemeChoice);
+    /// Paleta de tipos/stats usada nos próximos `show_detail`
+    fn set_palette(&self, palette: PaletteChoice);
+    fn set_cache_limits(&self, limits: CacheLimits);
+    /// Imagem do quiz (silhueta ou artwork revelada); `None` enquanto carrega
+    fn show_quiz_image(&self, image: Option<&Artwork>);
+    fn set_quiz_suggestions(&self, suggestions: &[Pokemon]);
+    fn set_quiz_status(&self, revealed: bool, feedback: &str, score: &QuizScore);
+    /// Pergunta do treino de tipos; `chosen` é a alternativa respondida
+    fn show_drill(&self, matchup: &Matchup, chosen: Option<usize>, stats: &DrillStats);
+}
+
+/// Estado compartilhado da aplicação
+struct State {
+    view: Vec<Pokemon>,                      // lista filtrada mostrada
+    selected: i32,                           // índice selecionado
+    detail: Option<u32>,                     // Pokémon cujo detalhe está (ou vai ficar) no painel
+    team: Vec<showdown::TeamMember>,         // time importado do Showdown
+    variant: SpriteVariant,                  // shiny/fêmea escolhidos no detalhe
+    settings: Settings,                      // preferências salvas
+    filter: String,                          // texto da busca aplicado
+    page: Page,                              // painel aberto
+    route: Route,                            // última rota publicada
+    routing: bool,                           // abrindo uma rota: não publica passos intermediários
+    quiz: Option<Round>,                     // rodada do quiz em andamento
+    quiz_generation: u8,                     // geração sorteada no quiz (0 = todas)
+    drill: Option<(Matchup, Option<usize>)>, // pergunta do treino e a resposta dada
+    size_reference: Option<SizeReference>,   // comparação de tamanho (None = pessoa)
+}
+
+impl State {
+    fn selected_id(&self) -> Option<u32> {
+        usize::try_from(self.selected)
+            .ok()
+            .and_then(|i| self.view.get(i))
+            .map(|p| p.id)
+    }
+
+    /// Rota que descreve a tela agora
+    fn current_route(&self) -> Route {
+        match self.page {
+            Page::Team => Route::Team,
+            Page::Quiz => Route::Quiz,
+            Page::Drill => Route::Drill,
+            Page::Settings => Route::Settings,
+            Page::Pokedex => match self.selected_id() {
+                Some(id) => Route::Pokemon(id),
+                None if self.filter.trim().is_empty() => Route::Home,
+                None => Route::Search(self.filter.trim().to_string()),
+            },
+        }
+    }
+}
+
+/// Lógica da tela, igual em todas as plataformas. Tela, executor, dados e ajustes
+/// entram pelo construtor, então roda em testes sem janela, rede nem disco.
+#[derive(Clone)]
+pub struct Controller<V: View, E: Executor, D: DataSource, S: SettingsStore> {
+    view: V,
+    exec: E,
+    source: D,
+    store: S,
+    state: Arc<Mutex<State>>,
+    artworks: Arc<Mutex<ArtworkCache<(u32, SpriteVariant)>>>,
+    thumbs: Arc<Mutex<Thumbs>>,
+    /// Incrementa a cada edição da busca; só o último debounce aplica o filtro
+    filter_seq: Arc<AtomicU64>,
+}
+
+impl<V: View, E: Executor, D: DataSource, S: SettingsStore> Controller<V, E, D, S> {
+    pub fn new(view: V, exec: E, source: D, store: S) -> Self {
+        let settings = store.load();
+        source.set_cache_size(settings.cache.details());
+        Self {
+            view,
+            exec,
+            source,
+            store,
+            artworks: Arc::new(Mutex::new(ArtworkCache::new(
+                settings.cache.artwork_bytes(),
+            ))),
+            thumbs: Arc::new(Mutex::new(Thumbs::default())),
+            state: Arc::new(Mutex::new(State {
+                view: POKEMON_LIST.to_vec(),
+                selected: -1,
+                detail: None,
+                team: Vec::new(),
+                variant: SpriteVariant::default(),
+                settings,
+                filter: String::new(),
+                page: Page::default(),
+                route: Route::default(),
+                routing: false,
+                quiz: None,
+                quiz_generation: 0,
+                drill: None,
+                size_reference: None,
+            })),
+            filter_seq: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Estado inicial + splash, já na rota pedida (hash da URL ou argumentos).
+    /// Sem rota, reabre a busca e o Pokémon da última sessão.
+    pub fn start(&self, route: Route) {
+        let settings = self.state.lock().unwrap().settings.clone();
+        self.view.set_theme(settings.theme);
+        self.view.set_palette(settings.palette);
+        self.view.set_text_rows(settings.text_rows);
+        self.view.set_grid_view(settings.grid_view);
+        self.view.set_units(settings.units);
+        self.view.set_language(settings.language);
+        self.view.set_cache_limits(settings.cache);
+        self.view.set_filter("");
+        self.view.set_selected_index(-1);
+        self.view.show_error("");
+        self.request_load();
+        let route = match route {
+            Route::Home => self.restore_session(&settings.session),
+            route => route,
+        };
+        self.open_route(route);
+
+        let view = self.view.clone();
+        self.exec.run_after(SPLASH, move || view.hide_splash());
+    }
+
+    /// Aplica a busca salva e devolve a rota que termina a restauração
+    fn restore_session(&self, session: &Session) -> Route {
+        let search = session.search.trim();
+        match session.pokemon {
+            Some(id) => {
+                // filtra antes para o Pokémon reabrir dentro da mesma busca
+                if !search.is_empty() {
+                    self.state.lock().unwrap().routing = true;
+                    self.view.set_filter(search);
+                    self.apply_filter(search);
+                    self.state.lock().unwrap().routing = false;
+                }
+                Route::Pokemon(id)
+            }
+            None if search.is_empty() => Route::Home,
+            None => Route::Search(search.to_string()),
+        }
+    }
+
+    /// Recarrega a lista completa
+    pub fn request_load(&self) {
+        {
+            let mut state = self.state.lock().unwrap();
+            state.selected = -1;
+            state.view = POKEMON_LIST.to_vec();
+            state.filter.clear();
+        }
+        self.view.set_selected_index(-1);
+        self.view.set_rows(POKEMON_LIST);
+        self.sync_route();
+    }
+
+    /// Texto da busca mudou: filtra depois de uma pausa na digitação
+    pub fn filter_edited(&self, filter: &str) {
+        let seq = self.filter_seq.fetch_add(1, Ordering::SeqCst) + 1;
+        let ctrl = self.clone();
+        let filter = filter.to_string();
+        self.exec.run_after(FILTER_DEBOUNCE, move || {
+            if ctrl.filter_seq.load(Ordering::SeqCst) == seq {
+                ctrl.apply_filter(&filter);
+            }
+        });
+    }
+
+    /// Filtra já (Enter); mantém a seleção se ela continuar na lista
+    pub fn apply_filter(&self, filter: &str) {
+        // cancela um debounce pendente
+        self.filter_seq.fetch_add(1, Ordering::SeqCst);
+
+        let filtered = search_pokemon(filter);
+        let selected = {
+            let mut state = self.state.lock().unwrap();
+            state.filter = filter.to_string();
+            let selected_id = state.selected_id();
+            state.selected = selected_id
+                .and_then(|id| filtered.iter().position(|p| p.id == id))
+                .map_or(-1, |i| i as i32);
+            // no quiz o painel mostra a resposta, não a seleção
+            if state.selected < 0 && state.page != Page::Quiz {
+                state.detail = None;
+            }
+            state.selected
+        };
+        self.view.set_rows(&filtered);
+        self.view.set_selected_index(selected);
+        self.state.lock().unwrap().view = filtered;
+        self.sync_route();
+    }
+
+    /// Índice da primeira linha da geração na lista filtrada (-1 se não houver)
+    pub fn generation_start(&self, generation: u8) -> i32 {
+        self.state
+            .lock()
+            .unwrap()
+            .view
+            .iter()
+            .position(|p| p.generation == generation)
+            .map_or(-1, |i| i as i32)
+    }
+
+    /// Busca por digitação: a partir de `from`, dando a volta, a primeira linha cujo nome
+    /// ou número começa com `prefix`; repetir uma letra ("ppp") percorre os nomes com ela
+    pub fn find_prefix(&self, prefix: &str, from: i32) -> i32 {
+        let prefix = prefix.to_lowercase();
+        let Some(first) = prefix.chars().next() else {
+            return -1;
+        };
+        let state = self.state.lock().unwrap();
+        let rows = &state.view;
+        if rows.is_empty() {
+            return -1;
+        }
+        let matches = |p: &Pokemon, prefix: &str| {
+            p.name.to_lowercase().starts_with(prefix) || p.id.to_string().starts_with(prefix)
+        };
+        let start = (from.max(0) as usize) % rows.len();
+        let find = |prefix: &str, start: usize| {
+            (0..rows.len())
+                .map(|k| (start + k) % rows.len())
+                .find(|&i| matches(&rows[i], prefix))
+        };
+        let found = find(&prefix, start).or_else(|| {
+            let repeated = prefix.chars().all(|c| c == first);
+            // a busca do "ppp" parte da linha seguinte à atual
+            repeated
+                .then(|| find(&first.to_string(), (start + 1) % rows.len()))
+                .flatten()
+        });
+        found.map_or(-1, |i| i as i32)
+    }
+
+    /// Miniatura de uma linha que apareceu: do cache ou para a fila de downloads
+    pub fn request_thumb(&self, id: u32) {
+        let cached = self.thumbs.lock().unwrap().get(id);
+        if let Some(thumb) = cached {
+            // a linha ainda está sendo criada; mexe no modelo depois
+            let view = self.view.clone();
+            self.exec
+                .run_after(Duration::ZERO, move || view.set_thumb(id, &thumb));
+            return;
+        }
+        self.thumbs.lock().unwrap().request(id);
+        self.pump_thumbs();
+    }
+
+    /// Abre downloads enquanto houver vaga; cada um segue pegando o próximo da fila
+    fn pump_thumbs(&self) {
+        loop {
+            let Some(mut id) = self.thumbs.lock().unwrap().next() else {
+                break;
+            };
+            let source = self.source.clone();
+            let exec = self.exec.clone();
+            let view = self.view.clone();
+            let thumbs = self.thumbs.clone();
+            self.exec.spawn(async move {
+                loop {
+                    let thumb = match source.sprite(id).await {
+                        Ok(bytes) => exec.decode_artwork(bytes, THUMB_SIZE).await.ok(),
+                        Err(_) => None,
+                    };
+                    thumbs.lock().unwrap().done(id, thumb.clone());
+                    if let Some(thumb) = thumb {
+                        let view = view.clone();
+                        exec.run_on_ui(move || view.set_thumb(id, &thumb));
+                    }
+                    let next = thumbs.lock().unwrap().next();
+                    match next {
+                        Some(next) => id = next,
+                        None => break,
+                    }
+                }
+            });
+        }
+    }
+
+    /// Seleciona a linha `idx` da lista filtrada e carrega o detalhe
+    pub fn select(&self, idx: i32) {
+        if idx < 0 {
+            return;
+        }
+        let id_pokemon = {
+            let mut state = self.state.lock().unwrap();
+            state.selected = idx;
+            state.detail = state.selected_id();
+            state.detail
+        };
+        self.view.show_loading(idx);
+        self.sync_route();
+        let Some(id_pokemon) = id_pokemon else {
+            return;
+        };
+        self.load_detail(id_pokemon);
+    }
+
+    /// Limpa a seleção (o detalhe fica vazio)
+    fn deselect(&self) {
+        {
+            let mut state = self.state.lock().unwrap();
+            state.selected = -1;
+            state.detail = None;
+        }
+        self.view.set_selected_index(-1);
+        self.view.show_error("");
+    }
+
+    /// Troca a variante da artwork (shiny/fêmea) e recarrega o detalhe aberto
+    pub fn set_variant(&self, variant: SpriteVariant) {
+        let id_pokemon = {
+            let mut state = self.state.lock().unwrap();
+            state.variant = variant;
+            state.detail
+        };
+        if let Some(id_pokemon) = id_pokemon {
+            self.load_detail(id_pokemon);
+        }
+    }
+
+    /// Mostra o detalhe do cache ou busca na PokeAPI em segundo plano
+    fn load_detail(&self, id_pokemon: u32) {
+        let variant = self.state.lock().unwrap().variant;
+        let key = (id_pokemon, variant);
+        if let Some(detail) = self.source.cached_detail(id_pokemon)
+            && let Some(artwork) = self.artworks.lock().unwrap().get(&key)
+        {
+            let reference = self.state.lock().unwrap().size_reference.clone();
+            self.view.show_detail(&detail, Some(&artwork));
+            self.view.show_size(reference.as_ref());
+            return;
+        }
+
+        let max_side = self.view.artwork_size();
+        let source = self.source.clone();
+        let exec = self.exec.clone();
+        let view = self.view.clone();
+        let state = self.state.clone();
+        let artworks = self.artworks.clone();
+        self.exec.spawn(async move {
+            let detail = source.detail(id_pokemon).await;
+            let artwork = match &detail {
+                Ok(_) => match source.artwork_variant(id_pokemon, variant).await {
+                    Ok(bytes) => exec.decode_artwork(bytes, max_side).await.ok(),
+                    Err(_) => None,
+                },
+                Err(_) => None,
+            };
+            if let Some(artwork) = &artwork {
+                artworks.lock().unwrap().put(key, artwork.clone());
+            }
+            exec.run_on_ui(move || {
+                // ignora respostas de uma seleção/variante que já mudou
+                let reference = {
+                    let state = state.lock().unwrap();
+                    if state.detail != Some(id_pokemon) || state.variant != variant {
+                        return;
+                    }
+                    state.size_reference.clone()
+                };
+                match detail {
+                    Ok(detail) => {
+                        view.show_detail(&detail, artwork.as_ref());
+                        view.show_size(reference.as_ref());
+                    }
+                    Err(_) => view.show_error("Falha ao carregar detalhes"),
+                }
+            });
+        });
+    }
+
+    // =================== Comparação de tamanho ===================
+    /// Compara com outro Pokémon (nome ou número, ou o primeiro da busca); vazio volta à pessoa
+    pub fn set_size_reference(&self, query: &str) {
+        let query = query.trim();
+        let pokemon = if query.is_empty() {
+            None
+        } else {
+            let found = find_pokemon(query).or_else(|| search_pokemon(query).into_iter().next());
+            let Some(pokemon) = found else {
+                self.view.show_size_error("Nenhum Pokémon encontrado");
+                return;
+            };
+            Some(pokemon)
+        };
+
+        let key = pokemon.map(|p| (p.id, SpriteVariant::default()));
+        let reference = pokemon.map(|pokemon| SizeReference {
+            pokemon,
+            height: self
+                .source
+                .cached_detail(pokemon.id)
+                .map_or(0, |detail| detail.height),
+            artwork: key.and_then(|key| self.artworks.lock().unwrap().get(&key)),
+        });
+        self.state.lock().unwrap().size_reference = reference.clone();
+        self.view.show_size(reference.as_ref());
+        let (Some(pokemon), Some(key)) = (pokemon, key) else {
+            return;
+        };
+        if reference.is_some_and(|r| r.height != 0 && r.artwork.is_some()) {
+            return;
+        }
+
+        let max_side = self.view.artwork_size();
+        let source = self.source.clone();
+        let exec = self.exec.clone();
+        let view = self.view.clone();
+        let state = self.state.clone();
+        let artworks = self.artworks.clone();
+        self.exec.spawn(async move {
+            let height = source.detail(pokemon.id).await.map(|detail| detail.height);
+            let artwork = match &height {
+                Ok(_) => match source.artwork_variant(pokemon.id, key.1).await {
+                    Ok(bytes) => exec.decode_artwork(bytes, max_side).await.ok(),
+                    Err(_) => None,
+                },
+                Err(_) => None,
+            };
+            if let Some(artwork) = &artwork {
+                artworks.lock().unwrap().put(key, artwork.clone());
+            }
+            exec.run_on_ui(move || {
+                size_reference_ready(&state, &view, pokemon.id, height, artwork)
+            });
+        });
+    }
+
+    // =================== Ajustes ===================
+    pub fn set_theme(&self, theme: ThemeChoice) {
+        {
+            let mut state = self.state.lock().unwrap();
+            state.settings.theme = theme;
+            self.store.save(&state.settings);
+        }
+        self.view.set_theme(theme);
+    }
+
+    /// Troca a paleta e redesenha o detalhe aberto
+    pub fn set_palette(&self, palette: PaletteChoice) {
+        let id_pokemon = {
+            let mut state = self.state.lock().unwrap();
+            state.settings.palette = palette;
+            self.store.save(&state.settings);
+            state.detail
+        };
+        self.view.set_palette(palette);
+        if let Some(id_pokemon) = id_pokemon {
+            self.load_detail(id_pokemon);
+        }
+        // chips do treino também usam a paleta
+        if self.state.lock().unwrap().drill.is_some() {
+            self.drill_resume();
+        }
+    }
+
+    /// Lista só com texto ou com sprites e tipos
+    pub fn set_text_rows(&self, on: bool) {
+        {
+            let mut state = self.state.lock().unwrap();
+            state.settings.text_rows = on;
+            self.store.save(&state.settings);
+        }
+        self.view.set_text_rows(on);
+    }
+
+    /// Troca as unidades e reformata o detalhe aberto
+    pub fn set_units(&self, units: Units) {
+        let id_pokemon = {
+            let mut state = self.state.lock().unwrap();
+            state.settings.units = units;
+            self.store.save(&state.settings);
+            state.detail
+        };
+        self.view.set_units(units);
+        if let Some(id_pokemon) = id_pokemon {
+            self.load_detail(id_pokemon);
+        }
+    }
+
+    /// Troca o idioma dos números e reformata o detalhe aberto
+    pub fn set_language(&self, language: Language) {
+        let id_pokemon = {
+            let mut state = self.state.lock().unwrap();
+            state.settings.language = language;
+            self.store.save(&state.settings);
+            state.detail
+        };
+        self.view.set_language(language);
+        if let Some(id_pokemon) = id_pokemon {
+            self.load_detail(id_pokemon);
+        }
+    }
+
+    /// Alterna entre lista e grade de cartões
+    pub fn set_grid_view(&self, on: bool) {
+        {
+            let mut state = self.state.lock().unwrap();
+            state.settings.grid_view = on;
+            self.store.save(&state.settings);
+        }
+        self.view.set_grid_view(on);
+    }
+
+    /// Novos limites de cache; valem na hora (o excedente é descartado)
+    pub fn set_cache_limits(&self, limits: CacheLimits) {
+        let limits = limits.clamped();
+        {
+            let mut state = self.state.lock().unwrap();
+            state.settings.cache = limits;
+            self.store.save(&state.settings);
+        }
+        self.source.set_cache_size(limits.details());
+        self.artworks
+            .lock()
+            .unwrap()
+            .set_budget(limits.artwork_bytes());
+        self.view.set_cache_limits(limits);
+    }
+
+    /// Geometria salva da janela
+    #[cfg(not(any(target_arch = "wasm32", target_os = "android")))]
+    pub fn window(&self) -> Option<WindowGeometry> {
+        self.state.lock().unwrap().settings.window
+    }
+
+    /// Guarda a geometria da janela (ao fechar)
+    #[cfg(not(any(target_arch = "wasm32", target_os = "android")))]
+    pub fn set_window(&self, geometry: WindowGeometry) {
+        let mut state = self.state.lock().unwrap();
+        state.settings.window = Some(geometry);
+        self.store.save(&state.settings);
+    }
+
+    // =================== Quiz ===================
+    /// Sorteia um novo Pokémon e carrega a silhueta
+    pub fn quiz_next(&self) {
+        let (answer, score) = {
+            let mut state = self.state.lock().unwrap();
+            let last = state.quiz.as_ref().map(|round| round.answer.id);
+            let Some(answer) = quiz::pick(state.quiz_generation, last) else {
+                return;
+            };
+            state.quiz = Some(Round {
+                answer,
+                artwork: None,
+                revealed: false,
+            });
+            (answer, state.settings.quiz)
+        };
+        self.view.set_quiz_status(false, "", &score);
+        self.view.set_quiz_suggestions(&[]);
+        self.view.show_quiz_image(None);
+
+        let key = (answer.id, SpriteVariant::default());
+        let cached = self.artworks.lock().unwrap().get(&key);
+        if let Some(artwork) = cached {
+            let silhouette = artwork.silhouette();
+            quiz_artwork_ready(&self.state, &self.view, answer.id, artwork, silhouette);
+            return;
+        }
+
+        let max_side = self.view.artwork_size();
+        let source = self.source.clone();
+        let exec = self.exec.clone();
+        let view = self.view.clone();
+        let state = self.state.clone();
+        let artworks = self.artworks.clone();
+        self.exec.spawn(async move {
+            let artwork = match source.artwork_variant(answer.id, key.1).await {
+                Ok(bytes) => exec.decode_artwork(bytes, max_side).await.ok(),
+                Err(_) => None,
+            };
+            let Some(artwork) = artwork else {
+                exec.run_on_ui(move || {
+                    let current = state.lock().unwrap().quiz.as_ref().map(|r| r.answer.id);
+                    if current == Some(answer.id) {
+                        view.set_quiz_status(false, "Falha ao carregar a imagem", &score);
+                    }
+                });
+                return;
+            };
+            artworks.lock().unwrap().put(key, artwork.clone());
+            let silhouette = artwork.silhouette();
+            exec.run_on_ui(move || {
+                quiz_artwork_ready(&state, &view, answer.id, artwork, silhouette)
+            });
+        });
+    }
+
+    /// Abriu a página do quiz: continua a rodada ou começa uma
+    fn quiz_resume(&self) {
+        let round = {
+            let state = self.state.lock().unwrap();
+            state
+                .quiz
+                .as_ref()
+                .map(|round| (round.answer.id, round.revealed))
+        };
+        match round {
+            None => self.quiz_next(),
+            Some((id_pokemon, true)) => {
+                self.state.lock().unwrap().detail = Some(id_pokemon);
+                self.load_detail(id_pokemon);
+            }
+            Some((_, false)) => {}
+        }
+    }
+
+    /// Texto do palpite mudou: sugere nomes com a busca da lista
+    pub fn quiz_edited(&self, text: &str) {
+        self.view.set_quiz_suggestions(&quiz::suggestions(text));
+    }
+
+    /// Confere o palpite e revela o Pokémon
+    pub fn quiz_guess(&self, text: &str) {
+        match quiz::resolve(text) {
+            Some(guess) => self.quiz_reveal(Some(guess)),
+            None => {
+                let (revealed, score) = {
+                    let state = self.state.lock().unwrap();
+                    let revealed = state.quiz.as_ref().is_none_or(|round| round.revealed);
+                    (revealed, state.settings.quiz)
+                };
+                if !revealed {
+                    self.view
+                        .set_quiz_status(false, "Nenhum Pokémon com esse nome", &score);
+                }
+            }
+        }
+    }
+
+    /// Desiste da rodada (conta como erro)
+    pub fn quiz_give_up(&self) {
+        self.quiz_reveal(None);
+    }
+
+    fn quiz_reveal(&self, guess: Option<Pokemon>) {
+        let (answer, artwork, score, correct) = {
+            let mut state = self.state.lock().unwrap();
+            let Some(round) = state.quiz.as_mut().filter(|round| !round.revealed) else {
+                return;
+            };
+            round.revealed = true;
+            let (answer, artwork) = (round.answer, round.artwork.clone());
+            let correct = guess.is_some_and(|g| g.id == answer.id);
+            state.settings.quiz.record(correct);
+            self.store.save(&state.settings);
+            state.detail = Some(answer.id);
+            (answer, artwork, state.settings.quiz, correct)
+        };
+        let feedback = match guess {
+            _ if correct => format!("Acertou! É {}.", answer.name),
+            Some(guess) => format!(
+                "Não foi dessa vez: era {}, não {}.",
+                answer.name, guess.name
+            ),
+            None => format!("Era {}.", answer.name),
+        };
+        self.view.set_quiz_status(true, &feedback, &score);
+        self.view.set_quiz_suggestions(&[]);
+        self.view.show_quiz_image(artwork.as_ref());
+        self.view.show_error("");
+        self.load_detail(answer.id);
+    }
+
+    /// Limita o sorteio a uma geração (0 = todas) e começa outra rodada
+    pub fn set_quiz_generation(&self, generation: u8) {
+        self.state.lock().unwrap().quiz_generation = generation;
+        self.quiz_next();
+    }
+
+    // =================== Treino de tipos ===================
+    /// Próxima pergunta (revisões vencidas primeiro)
+    pub fn drill_next(&self) {
+        let (matchup, stats) = {
+            let mut state = self.state.lock().unwrap();
+            let last = state.drill.map(|(matchup, _)| matchup);
+            let matchup = state.settings.drill.next(last);
+            state.drill = Some((matchup, None));
+            (matchup, state.settings.drill.clone())
+        };
+        self.view.show_drill(&matchup, None, &stats);
+    }
+
+    /// Mostra de novo a pergunta atual, ou começa o treino
+    fn drill_resume(&self) {
+        let current = {
+            let state = self.state.lock().unwrap();
+            state
+                .drill
+                .map(|(matchup, chosen)| (matchup, chosen, state.settings.drill.clone()))
+        };
+        match current {
+            Some((matchup, chosen, stats)) => self.view.show_drill(&matchup, chosen, &stats),
+            None => self.drill_next(),
+        }
+    }
+
+    /// Responde a pergunta atual com a alternativa `choice` de `drill::CHOICES`
+    pub fn drill_answer(&self, choice: usize) {
+        if choice >= drill::CHOICES.len() {
+            return;
+        }
+        let (matchup, stats) = {
+            let mut state = self.state.lock().unwrap();
+            let Some((matchup, None)) = state.drill else {
+                return;
+            };
+            state.drill = Some((matchup, Some(choice)));
+            state
+                .settings
+                .drill
+                .record(&matchup, choice == matchup.answer());
+            self.store.save(&state.settings);
+            (matchup, state.settings.drill.clone())
+        };
+        self.view.show_drill(&matchup, Some(choice), &stats);
+    }
+
+    // =================== Time (Showdown) ===================
+    /// Importa o texto colado
+    pub fn import_team(&self, text: &str) {
+        match showdown::parse_team(text) {
+            Ok(team) => {
+                self.view.set_team(&team);
+                self.view.set_team_error("");
+                self.state.lock().unwrap().team = team;
+            }
+            Err(e) => self.view.set_team_error(&e.to_string()),
+        }
+    }
+
+    /// Exporta o time atual de volta para o texto
+    pub fn export_team(&self) {
+        let text = showdown::format_team(&self.state.lock().unwrap().team);
+        self.view.set_team_text(&text);
+        self.view.set_team_error("");
+    }
+
+    /// Abre o Pokémon do time no painel de detalhes
+    pub fn open_team_member(&self, species_id: u32) {
+        let Some(idx) = POKEMON_LIST.iter().position(|p| p.id == species_id) else {
+            return;
+        };
+        self.view.set_filter("");
+        self.apply_filter("");
+        self.set_page(Page::Pokedex);
+        self.select(idx as i32);
+    }
+
+    // =================== Navegação ===================
+    /// Troca o painel principal (Pokédex, Time ou Ajustes)
+    pub fn set_page(&self, page: Page) {
+        let previous = std::mem::replace(&mut self.state.lock().unwrap().page, page);
+        self.view.show_page(page);
+        if previous != page {
+            if page == Page::Quiz {
+                self.quiz_resume();
+            } else if previous == Page::Quiz {
+                self.show_selected();
+            }
+            if page == Page::Drill {
+                self.drill_resume();
+            }
+        }
+        self.sync_route();
+    }
+
+    /// Volta o painel de detalhes para o Pokémon selecionado na lista
+    fn show_selected(&self) {
+        let id_pokemon = {
+            let mut state = self.state.lock().unwrap();
+            state.detail = state.selected_id();
+            state.detail
+        };
+        match id_pokemon {
+            Some(id_pokemon) => self.load_detail(id_pokemon),
+            None => self.view.show_error(""),
+        }
+    }
+
+    /// Leva a tela até a rota (hash da URL, voltar/avançar ou argumentos)
+    pub fn open_route(&self, route: Route) {
+        {
+            let mut state = self.state.lock().unwrap();
+            if state.route == route {
+                return;
+            }
+            state.routing = true;
+            state.route = route.clone();
+        }
+        match route {
+            Route::Home => {
+                self.view.set_filter("");
+                self.apply_filter("");
+                self.deselect();
+                self.set_page(Page::Pokedex);
+            }
+            Route::Search(query) => {
+                self.view.set_filter(&query);
+                self.apply_filter(&query);
+                self.deselect();
+                self.set_page(Page::Pokedex);
+            }
+            Route::Pokemon(id) => {
+                let in_view = self.state.lock().unwrap().view.iter().any(|p| p.id == id);
+                if !in_view {
+                    self.view.set_filter("");
+                    self.apply_filter("");
+                }
+                self.set_page(Page::Pokedex);
+                let idx = self
+                    .state
+                    .lock()
+                    .unwrap()
+                    .view
+                    .iter()
+                    .position(|p| p.id == id);
+                if let Some(idx) = idx {
+                    self.select(idx as i32);
+                }
+            }
+            Route::Team => self.set_page(Page::Team),
+            Route::Quiz => self.set_page(Page::Quiz),
+            Route::Drill => self.set_page(Page::Drill),
+            Route::Settings => self.set_page(Page::Settings),
+        }
+        self.state.lock().unwrap().routing = false;
+        // a rota pedida pode não existir (ex.: id fora da lista): corrige sem empilhar
+        self.publish_route(true);
+    }
+
+    /// Publica a rota se a tela mudou
+    fn sync_route(&self) {
+        self.publish_route(false);
+    }
+
+    fn publish_route(&self, replace: bool) {
+        let (route, replace) = {
+            let mut state = self.state.lock().unwrap();
+            if state.routing {
+                return;
+            }
+            let route = state.current_route();
+            if route == state.route {
+                return;
+            }
+            // digitar na busca não enche o histórico
+            let replace =
+                replace || matches!((&state.route, &route), (Route::Search(_), Route::Search(_)));
+            state.route = route.clone();
+
+            // a sessão salva acompanha a busca e o Pokémon aberto
+            let session = match &route {
+                Route::Home => Some(Session::default()),
+                Route::Search(query) => Some(Session {
+                    search: query.clone(),
+                    pokemon: None,
+                }),
+                Route::Pokemon(id) => Some(Session {
+                    search: state.filter.trim().to_string(),
+                    pokemon: Some(*id),
+                }),
+                _ => None,
+            };
+            if let Some(session) = session
+                && session != state.settings.session
+            {
+                state.settings.session = session;
+                self.store.save(&state.settings);
+            }
+            (route, replace)
+        };
+        self.view.set_route(&route, replace);
+    }
+}
+
+/// Altura e artwork da referência chegaram: mostra se ela ainda é a escolhida
+fn size_reference_ready<V: View>(
+    state: &Mutex<State>,
+    view: &V,
+    id_pokemon: u32,
+    height: Result<u32, String>,
+    artwork: Option<Artwork>,
+) {
+    let reference = {
+        let mut state = state.lock().unwrap();
+        let Some(reference) = state
+            .size_reference
+            .as_mut()
+            .filter(|r| r.pokemon.id == id_pokemon)
+        else {
+            return;
+        };
+        match height {
+            Ok(height) => {
+                reference.height = height;
+                reference.artwork = artwork;
+                Some(reference.clone())
+            }
+            // sem altura não há o que comparar: volta para a pessoa
+            Err(_) => {
+                state.size_reference = None;
+                None
+            }
+        }
+    };
+    view.show_size(reference.as_ref());
+    if reference.is_none() {
+        view.show_size_error("Falha ao carregar a altura");
+    }
+}
+
+/// Artwork do quiz chegou: guarda para revelar e mostra a silhueta se a rodada continua
+fn quiz_artwork_ready<V: View>(
+    state: &Mutex<State>,
+    view: &V,
+    id_pokemon: u32,
+    artwork: Artwork,
+    silhouette: Option<Artwork>,
+) {
+    let revealed = {
+        let mut state = state.lock().unwrap();
+        let Some(round) = state.quiz.as_mut().filter(|r| r.answer.id == id_pokemon) else {
+            return;
+        };
+        round.artwork = Some(artwork.clone());
+        round.revealed
+    };
+    if revealed {
+        view.show_quiz_image(Some(&artwork));
+    } else {
+        view.show_quiz_image(silhouette.as_ref());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{gui::executor::MaybeSend, service::DetailSprites};
+    use std::{
+        cell::{Cell, RefCell},
+        future::Future,
+        num::NonZeroUsize,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    // =================== Tela falsa ===================
+    /// O que a "tela" mostra agora, mais o histórico de rotas
+    #[derive(Default)]
+    struct Screen {
+        rows: Vec<u32>,
+        selected: i32,
+        detail: Option<u32>,
+        routes: Vec<(Route, bool)>,
+    }
+
+    #[derive(Clone, Default)]
+    struct MockView(Arc<Mutex<Screen>>);
+
+    impl MockView {
+        fn screen(&self) -> std::sync::MutexGuard<'_, Screen> {
+            self.0.lock().unwrap()
+        }
+    }
+
+    impl View for MockView {
+        fn hide_splash(&self) {}
+        fn set_rows(&self, rows: &[Pokemon]) {
+            self.screen().rows = rows.iter().map(|p| p.id).collect();
+        }
+        fn set_thumb(&self, _: u32, _: &Artwork) {}
+        fn set_text_rows(&self, _: bool) {}
+        fn set_grid_view(&self, _: bool) {}
+        fn set_units(&self, _: Units) {}
+        fn set_language(&self, _: Language) {}
+        fn set_filter(&self, _: &str) {}
+        fn set_selected_index(&self, idx: i32) {
+            self.screen().selected = idx;
+        }
+        fn show_loading(&self, idx: i32) {
+            let mut screen = self.screen();
+            screen.selected = idx;
+            screen.detail = None;
+        }
+        fn show_detail(&self, detail: &Detail, _: Option<&Artwork>) {
+            self.screen().detail = Some(detail.id);
+        }
+        fn show_size(&self, _: Option<&SizeReference>) {}
+        fn show_size_error(&self, _: &str) {}
+        fn artwork_size(&self) -> u32 {
+            250
+        }
+        fn show_error(&self, _: &str) {}
+        fn set_team(&self, _: &[showdown::TeamMember]) {}
+        fn set_team_text(&self, _: &str) {}
+        fn set_team_error(&self, _: &str) {}
+        fn show_page(&self, _: Page) {}
+        fn set_route(&self, route: &Route, replace: bool) {
+            self.screen().routes.push((route.clone(), replace));
+        }
+        fn set_theme(&self, _: ThemeChoice) {}
+        fn set_palette(&self, _: PaletteChoice) {}
+        fn set_cache_limits(&self, _: CacheLimits) {}
+        fn show_quiz_image(&self, _: Option<&Artwork>) {}
+        fn set_quiz_suggestions(&self, _: &[Pokemon]) {}
+        fn set_quiz_status(&self, _: bool, _: &str, _: &QuizScore) {}
+        fn show_drill(&self, _: &Matchup, _: Option<usize>, _: &DrillStats) {}
+    }
+
+    // =================== Executor síncrono ===================
+    type Task = Box<dyn FnOnce()>;
+
+    thread_local! {
+        /// Relógio virtual e tarefas agendadas (cada teste roda na sua thread)
+        static NOW: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+        static TASKS: RefCell<Vec<(Duration, Task)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Nada roda sozinho: `advance` anda o relógio e executa o que venceu, em ordem
+    #[derive(Clone)]
+    struct SyncExecutor;
+
+    impl SyncExecutor {
+        fn schedule(delay: Duration, task: Task) {
+            let due = NOW.get() + delay;
+            TASKS.with_borrow_mut(|tasks| tasks.push((due, task)));
+        }
+
+        fn advance(by: Duration) {
+            NOW.set(NOW.get() + by);
+            loop {
+                let next = TASKS.with_borrow_mut(|tasks| {
+                    let now = NOW.get();
+                    // a primeira a vencer; empates na ordem de agendamento
+                    let i = (0..tasks.len())
+                        .filter(|&i| tasks[i].0 <= now)
+                        .min_by_key(|&i| tasks[i].0)?;
+                    Some(tasks.remove(i).1)
+                });
+                match next {
+                    Some(task) => task(),
+                    None => break,
+                }
+            }
+        }
+
+        /// Roda o que já está pendente, sem andar o relógio
+        fn settle() {
+            Self::advance(Duration::ZERO);
+        }
+    }
+
+    /// As fontes falsas respondem na hora: um `poll` basta
+    fn block_on<T>(fut: impl Future<Output = T>) -> T {
+        let mut cx = Context::from_waker(Waker::noop());
+        match pin!(fut).poll(&mut cx) {
+            Poll::Ready(value) => value,
+            Poll::Pending => panic!("a fonte de teste não deveria esperar"),
+        }
+    }
+
+    impl Executor for SyncExecutor {
+        fn spawn(&self, fut: impl Future<Output = ()> + MaybeSend + 'static) {
+            Self::schedule(Duration::ZERO, Box::new(move || block_on(fut)));
+        }
+
+        fn run_on_ui(&self, f: impl FnOnce() + Send + 'static) {
+            Self::schedule(Duration::ZERO, Box::new(f));
+        }
+
+        fn run_after(&self, delay: Duration, f: impl FnOnce() + 'static) {
+            Self::schedule(delay, Box::new(f));
+        }
+
+        fn decode_artwork(
+            &self,
+            bytes: Vec<u8>,
+            _: u32,
+        ) -> impl Future<Output = Result<Artwork, String>> + MaybeSend {
+            std::future::ready(Ok(Artwork::Svg(bytes.into())))
+        }
+    }
+
+    // =================== Dados e ajustes falsos ===================
+    /// Detalhes inventados na hora, sem rede
+    #[derive(Clone)]
+    struct FakeSource;
+
+    fn fake_detail(id: u32) -> Detail {
+        Detail {
+            id,
+            height: 7,
+            weight: 69,
+            types: Vec::new(),
+            stats: Vec::new(),
+            sprites: DetailSprites::default(),
+            ability1: String::new(),
+            ability2: String::new(),
+            hidden_ability: String::new(),
+        }
+    }
+
+    impl DataSource for FakeSource {
+        fn cached_detail(&self, _: u32) -> Option<Detail> {
+            None
+        }
+
+        fn detail(&self, id: u32) -> impl Future<Output = Result<Detail, String>> + MaybeSend {
+            std::future::ready(Ok(fake_detail(id)))
+        }
+
+        fn artwork_variant(
+            &self,
+            _: u32,
+            _: SpriteVariant,
+        ) -> impl Future<Output = Result<Vec<u8>, String>> + MaybeSend {
+            std::future::ready(Ok(Vec::new()))
+        }
+
+        fn sprite(&self, _: u32) -> impl Future<Output = Result<Vec<u8>, String>> + MaybeSend {
+            std::future::ready(Ok(Vec::new()))
+        }
+
+        fn set_cache_size(&self, _: NonZeroUsize) {}
+    }
+
+    #[derive(Clone, Default)]
+    struct MemoryStore(Arc<Mutex<Settings>>);
+
+    impl SettingsStore for MemoryStore {
+        fn load(&self) -> Settings {
+            self.0.lock().unwrap().clone()
+        }
+
+        fn save(&self, settings: &Settings) {
+            *self.0.lock().unwrap() = settings.clone();
+        }
+    }
+
+    type TestController = Controller<MockView, SyncExecutor, FakeSource, MemoryStore>;
+
+    fn started() -> (TestController, MockView, MemoryStore) {
+        let view = MockView::default();
+        let store = MemoryStore::default();
+        let ctrl = Controller::new(view.clone(), SyncExecutor, FakeSource, store.clone());
+        ctrl.start(Route::Home);
+        SyncExecutor::settle();
+        (ctrl, view, store)
+    }
+
+    fn index_of(view: &MockView, id: u32) -> i32 {
+        view.screen().rows.iter().position(|&r| r == id).unwrap() as i32
+    }
+
+    // =================== Testes ===================
+    #[test]
+    fn select_loads_detail() {
+        let (ctrl, view, _) = started();
+        ctrl.select(24);
+        assert_eq!(
+            view.screen().detail,
+            None,
+            "só depois da tarefa em segundo plano"
+        );
+        SyncExecutor::settle();
+        let screen = view.screen();
+        assert_eq!(screen.selected, 24);
+        assert_eq!(screen.detail, Some(25));
+    }
+
+    #[test]
+    fn filter_waits_for_typing_pause() {
+        let (ctrl, view, _) = started();
+        ctrl.filter_edited("p");
+        SyncExecutor::advance(FILTER_DEBOUNCE / 2);
+        ctrl.filter_edited("pika");
+        SyncExecutor::advance(FILTER_DEBOUNCE / 2);
+        // o primeiro debounce venceu, mas foi substituído
+        assert_eq!(view.screen().rows.len(), POKEMON_LIST.len());
+        SyncExecutor::advance(FILTER_DEBOUNCE);
+        assert!(view.screen().rows.contains(&25));
+        assert!(view.screen().rows.len() < 10);
+    }
+
+    #[test]
+    fn filter_keeps_selection_in_results() {
+        let (ctrl, view, _) = started();
+        ctrl.select(24);
+        SyncExecutor::settle();
+
+        ctrl.apply_filter("pika");
+        let pikachu = index_of(&view, 25);
+        assert_eq!(view.screen().selected, pikachu);
+
+        // fora do resultado, a seleção some
+        ctrl.apply_filter("char");
+        assert_eq!(view.screen().selected, -1);
+    }
+
+    #[test]
+    fn routes_follow_the_screen() {
+        let (ctrl, view, store) = started();
+        ctrl.apply_filter("pi");
+        ctrl.apply_filter("pika");
+        ctrl.select(index_of(&view, 25));
+        SyncExecutor::settle();
+
+        let routes = view.screen().routes.clone();
+        assert_eq!(
+            routes,
+            [
+                (Route::Search("pi".into()), false),
+                // continuar digitando substitui a entrada do histórico
+                (Route::Search("pika".into()), true),
+                (Route::Pokemon(25), false),
+            ]
+        );
+        // e a sessão salva acompanha
+        let session = store.load().session;
+        assert_eq!(session.search, "pika");
+        assert_eq!(session.pokemon, Some(25));
+    }
+}
@@ -0,0 +1,261 @@
+use crate::{PokemonType, helpers::type_label_pt};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Alternativas de resposta, na ordem dos botões
+pub const CHOICES: [f32; 6] = [0.0, 0.25, 0.5, 1.0, 2.0, 4.0];
+
+/// Rótulo das alternativas ("x½")
+pub const CHOICE_LABELS: [&str; 6] = ["x0", "x¼", "x½", "x1", "x2", "x4"];
+
+/// Intervalo (em perguntas) até rever um confronto, por nível de acerto
+const INTERVALS: [u64; 5] = [2, 5, 12, 30, 80];
+
+/// Quantos confrontos aparecem em "mais errados"
+const HARDEST: usize = 5;
+
+/// Um tipo atacando um Pokémon de um ou dois tipos
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Matchup {
+    pub attack: PokemonType,
+    pub defense: PokemonType,
+    pub defense2: Option<PokemonType>,
+}
+
+impl Matchup {
+    /// Confronto aleatório; metade das vezes com defensor de dois tipos
+    fn random() -> Self {
+        let pick = || PokemonType::ALL[fastrand::usize(..PokemonType::ALL.len())];
+        let attack = pick();
+        let defense = pick();
+        let defense2 = fastrand::bool().then(pick).filter(|t| *t != defense);
+        // ordem fixa para "Água/Terrestre" e "Terrestre/Água" serem o mesmo confronto
+        let (defense, defense2) = match defense2 {
+            Some(other) if (other as usize) < (defense as usize) => (other, Some(defense)),
+            _ => (defense, defense2),
+        };
+        Self {
+            attack,
+            defense,
+            defense2,
+        }
+    }
+
+    pub fn defenders(&self) -> Vec<PokemonType> {
+        std::iter::once(self.defense).chain(self.defense2).collect()
+    }
+
+    pub fn multiplier(&self) -> f32 {
+        self.attack.effectiveness_against(&self.defenders())
+    }
+
+    /// Índice da alternativa certa em `CHOICES`
+    pub fn answer(&self) -> usize {
+        let m = self.multiplier();
+        CHOICES.iter().position(|c| *c == m).unwrap_or(3)
+    }
+
+    /// Chave salva nas estatísticas ("fire>water/ground")
+    fn key(&self) -> String {
+        let defenders: Vec<&str> = self.defenders().iter().map(|t| t.id()).collect();
+        format!("{}>{}", self.attack.id(), defenders.join("/"))
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        let by_id = |id: &str| PokemonType::ALL.into_iter().find(|t| t.id() == id);
+        let (attack, defenders) = key.split_once('>')?;
+        let (defense, defense2) = match defenders.split_once('/') {
+            Some((a, b)) => (by_id(a)?, Some(by_id(b)?)),
+            None => (by_id(defenders)?, None),
+        };
+        Some(Self {
+            attack: by_id(attack)?,
+            defense,
+            defense2,
+        })
+    }
+
+    /// "Água/Terrestre"
+    pub fn defenders_pt(&self) -> String {
+        let labels: Vec<&str> = self.defenders().into_iter().map(type_label_pt).collect();
+        labels.join("/")
+    }
+
+    /// "Fogo → Água/Terrestre"
+    pub fn label_pt(&self) -> String {
+        format!("{} → {}", type_label_pt(self.attack), self.defenders_pt())
+    }
+}
+
+/// Acertos de um confronto e quando ele volta
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MatchupStats {
+    pub correct: u32,
+    pub answered: u32,
+    /// Nível na repetição espaçada (errar volta para 0)
+    pub level: u8,
+    /// Pergunta a partir da qual o confronto deve voltar
+    pub due: u64,
+}
+
+/// Histórico do treino, salvo nos ajustes
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DrillStats {
+    /// Perguntas respondidas até agora (o "relógio" da repetição espaçada)
+    pub turn: u64,
+    pub matchups: BTreeMap<String, MatchupStats>,
+}
+
+impl DrillStats {
+    /// Próxima pergunta: o confronto vencido há mais tempo ou, se nenhum, um novo
+    pub fn next(&self, last: Option<Matchup>) -> Matchup {
+        let last = last.map(|m| m.key());
+        let due = self
+            .matchups
+            .iter()
+            .filter(|(key, stats)| stats.due <= self.turn && Some(*key) != last.as_ref())
+            .min_by_key(|(_, stats)| stats.due)
+            .and_then(|(key, _)| Matchup::from_key(key));
+        if let Some(matchup) = due {
+            return matchup;
+        }
+        // prefere confrontos ainda não vistos
+        let mut matchup = Matchup::random();
+        for _ in 0..8 {
+            if !self.matchups.contains_key(&matchup.key()) && Some(matchup.key()) != last {
+                break;
+            }
+            matchup = Matchup::random();
+        }
+        matchup
+    }
+
+    /// Registra a resposta e agenda a revisão
+    pub fn record(&mut self, matchup: &Matchup, correct: bool) {
+        self.turn += 1;
+        let turn = self.turn;
+        let stats = self.matchups.entry(matchup.key()).or_default();
+        stats.answered += 1;
+        if correct {
+            stats.correct += 1;
+            stats.level = (stats.level + 1).min(INTERVALS.len() as u8 - 1);
+        } else {
+            stats.level = 0;
+        }
+        stats.due = turn + INTERVALS[stats.level as usize];
+    }
+
+    pub fn get(&self, matchup: &Matchup) -> MatchupStats {
+        self.matchups
+            .get(&matchup.key())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Confrontos errados na última vez (voltam logo)
+    pub fn to_review(&self) -> usize {
+        self.matchups
+            .values()
+            .filter(|s| s.level == 0 && s.correct < s.answered)
+            .count()
+    }
+
+    /// Os confrontos com mais erros
+    pub fn hardest(&self) -> Vec<(Matchup, MatchupStats)> {
+        let mut missed: Vec<(Matchup, MatchupStats)> = self
+            .matchups
+            .iter()
+            .filter(|(_, s)| s.correct < s.answered)
+            .filter_map(|(key, s)| Some((Matchup::from_key(key)?, *s)))
+            .collect();
+        missed.sort_by_key(|(_, s)| (std::cmp::Reverse(s.answered - s.correct), s.correct));
+        missed.truncate(HARDEST);
+        missed
+    }
+}
+
+/// Acertos em texto ("3/5 (60%)")
+pub fn accuracy_pt(correct: u32, answered: u32) -> String {
+    if answered == 0 {
+        return "0/0".into();
+    }
+    format!("{correct}/{answered} ({}%)", correct * 100 / answered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matchup(attack: PokemonType, defense: PokemonType) -> Matchup {
+        Matchup {
+            attack,
+            defense,
+            defense2: None,
+        }
+    }
+
+    /// Perguntas até o confronto voltar
+    fn wait(stats: &DrillStats, m: &Matchup) -> u64 {
+        stats.get(m).due - stats.turn
+    }
+
+    #[test]
+    fn wrong_answer_resets_level() {
+        let mut stats = DrillStats::default();
+        let m = matchup(PokemonType::Fire, PokemonType::Water);
+        for _ in 0..3 {
+            stats.record(&m, true);
+        }
+        assert_eq!(stats.get(&m).level, 3);
+
+        stats.record(&m, false);
+        let s = stats.get(&m);
+        assert_eq!((s.level, s.correct, s.answered), (0, 3, 4));
+        assert_eq!(wait(&stats, &m), INTERVALS[0]);
+        assert_eq!(stats.to_review(), 1);
+    }
+
+    #[test]
+    fn correct_answers_step_through_intervals() {
+        let mut stats = DrillStats::default();
+        let m = matchup(PokemonType::Grass, PokemonType::Ground);
+        let mut waits = Vec::new();
+        for _ in 0..INTERVALS.len() + 2 {
+            stats.record(&m, true);
+            waits.push(wait(&stats, &m));
+        }
+        let last = INTERVALS[INTERVALS.len() - 1];
+        assert_eq!(waits, [&INTERVALS[1..], &[last, last, last]].concat());
+        assert_eq!(usize::from(stats.get(&m).level), INTERVALS.len() - 1);
+    }
+
+    #[test]
+    fn next_prefers_due_matchups() {
+        let mut stats = DrillStats::default();
+        let missed = matchup(PokemonType::Electric, PokemonType::Ground);
+        stats.record(&missed, false);
+        stats.record(&matchup(PokemonType::Fire, PokemonType::Grass), true);
+        stats.record(&matchup(PokemonType::Water, PokemonType::Fire), true);
+        // o errado venceu; os acertados ainda não
+        assert_eq!(stats.get(&missed).due, stats.turn);
+        for _ in 0..20 {
+            assert_eq!(stats.next(None), missed);
+        }
+        // não repete a pergunta que acabou de sair
+        assert_ne!(stats.next(Some(missed)), missed);
+    }
+
+    #[test]
+    fn next_prefers_the_longest_overdue() {
+        let mut stats = DrillStats::default();
+        let first = matchup(PokemonType::Ice, PokemonType::Dragon);
+        let second = matchup(PokemonType::Rock, PokemonType::Flying);
+        stats.record(&first, false);
+        stats.record(&second, false);
+        stats.turn += 10;
+        assert_eq!(stats.next(None), first);
+        assert_eq!(stats.next(Some(first)), second);
+    }
+}
@@ -0,0 +1,99 @@
+use super::artwork::{self, Artwork};
+use std::{future::Future, time::Duration};
+
+/// `Send` só onde as tarefas rodam em outras threads (no wasm tudo é local)
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send> MaybeSend for T {}
+
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSend for T {}
+
+/// Onde o controller roda trabalho assíncrono e volta para a thread da UI
+pub trait Executor: Clone + MaybeSend + 'static {
+    /// Roda uma tarefa em segundo plano
+    fn spawn(&self, fut: impl Future<Output = ()> + MaybeSend + 'static);
+    /// Agenda `f` na thread da UI
+    fn run_on_ui(&self, f: impl FnOnce() + Send + 'static);
+    /// Agenda `f` na thread da UI depois de `delay`
+    fn run_after(&self, delay: Duration, f: impl FnOnce() + 'static);
+    /// Decodifica e reduz a artwork para `max_side` pixels sem travar a UI
+    fn decode_artwork(
+        &self,
+        bytes: Vec<u8>,
+        max_side: u32,
+    ) -> impl Future<Output = Result<Artwork, String>> + MaybeSend;
+}
+
+// =================== Desktop / Android ===================
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone)]
+pub struct TokioExecutor {
+    handle: tokio::runtime::Handle,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl TokioExecutor {
+    pub fn new(handle: tokio::runtime::Handle) -> Self {
+        Self { handle }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Executor for TokioExecutor {
+    fn spawn(&self, fut: impl Future<Output = ()> + MaybeSend + 'static) {
+        self.handle.spawn(fut);
+    }
+
+    fn run_on_ui(&self, f: impl FnOnce() + Send + 'static) {
+        slint::invoke_from_event_loop(f).ok();
+    }
+
+    fn run_after(&self, delay: Duration, f: impl FnOnce() + 'static) {
+        slint::Timer::single_shot(delay, f);
+    }
+
+    /// Numa thread de bloqueio do tokio
+    fn decode_artwork(
+        &self,
+        bytes: Vec<u8>,
+        max_side: u32,
+    ) -> impl Future<Output = Result<Artwork, String>> + MaybeSend {
+        let task = self
+            .handle
+            .spawn_blocking(move || artwork::decode_scaled(&bytes, max_side));
+        async move { task.await.map_err(|e| e.to_string())? }
+    }
+}
+
+// =================== WebAssembly ===================
+#[cfg(target_arch = "wasm32")]
+#[derive(Clone)]
+pub struct WasmExecutor;
+
+#[cfg(target_arch = "wasm32")]
+impl Executor for WasmExecutor {
+    fn spawn(&self, fut: impl Future<Output = ()> + MaybeSend + 'static) {
+        wasm_bindgen_futures::spawn_local(fut);
+    }
+
+    fn run_on_ui(&self, f: impl FnOnce() + Send + 'static) {
+        slint::invoke_from_event_loop(f).ok();
+    }
+
+    fn run_after(&self, delay: Duration, f: impl FnOnce() + 'static) {
+        slint::Timer::single_shot(delay, f);
+    }
+
+    /// Sem threads: reduz em blocos, cedendo a vez à UI entre eles
+    fn decode_artwork(
+        &self,
+        bytes: Vec<u8>,
+        max_side: u32,
+    ) -> impl Future<Output = Result<Artwork, String>> + MaybeSend {
+        artwork::decode_scaled_chunked(bytes, max_side)
+    }
+}
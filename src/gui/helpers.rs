@@ -0,0 +1,176 @@
+use super::{artwork, settings::PaletteChoice};
+use crate::{PokemonColor, PokemonType, Stat};
+use rust_embed::Embed;
+use slint::{Brush, Color};
+use std::collections::HashMap;
+
+// ÍCONES DE TIPOS
+#[derive(Embed)]
+#[folder = "imagens/tipos/"] // embute toda a pasta
+struct TypeIcons;
+
+/// Cores por tipo para daltonismo, na ordem de `PokemonType::ALL`.
+/// Variam mais em luminosidade e no eixo que cada deficiência preserva.
+const DEUTERANOPIA_TYPES: [u32; 18] = [
+    0x9e9e9e, 0xe69f00, 0x0072b2, 0xf0e442, 0x7a8f3a, 0x56b4e9, 0x8c4b00, 0x7b3294, 0xc9a36b,
+    0xa6cee3, 0xcc79a7, 0xb8b800, 0x6b5a3c, 0x3b3b7a, 0x1f3fbf, 0x2b2b2b, 0xb0bec5, 0xf4b6d2,
+];
+const PROTANOPIA_TYPES: [u32; 18] = [
+    0x9e9e9e, 0xffb000, 0x0072b2, 0xfff275, 0x7a8f3a, 0x56b4e9, 0xa65e00, 0x6a3d9a, 0xd4b483,
+    0xa6cee3, 0xb07aa1, 0xc2c200, 0x6b5a3c, 0x3b3b7a, 0x1f3fbf, 0x2b2b2b, 0xb0bec5, 0xf7c6dc,
+];
+const TRITANOPIA_TYPES: [u32; 18] = [
+    0x9e9e9e, 0xe4572e, 0x00868b, 0xff9eb5, 0x2e7d32, 0x7fd6d6, 0x8b1a1a, 0xb0307a, 0xc07a55,
+    0x9fc5c5, 0xff5c8a, 0x4caf50, 0x6d4c41, 0x37474f, 0x005f73, 0x212121, 0xa7b8b8, 0xf8bbd0,
+];
+
+/// Cores por stat para daltonismo, na ordem de `Stat` (HP, Atk, Def, SpA, SpD, Spe).
+/// Deuteranopia e protanopia trocam verde/vermelho por amarelo/azul (na protanopia o
+/// vermelho escurece, então os tons quentes ficam mais claros); a tritanopia troca
+/// azul/amarelo por verde, vermelho e ciano.
+const DEUTERANOPIA_STATS: [u32; 6] = [0xf0e442, 0xe69f00, 0x8c4b00, 0x56b4e9, 0x0072b2, 0xcc79a7];
+const PROTANOPIA_STATS: [u32; 6] = [0xfff275, 0xffb000, 0xa65e00, 0x56b4e9, 0x1f3fbf, 0xb07aa1];
+const TRITANOPIA_STATS: [u32; 6] = [0x2e7d32, 0xe4572e, 0x8b1a1a, 0x7fd6d6, 0x00868b, 0xff5c8a];
+
+fn hex(rgb: u32) -> Brush {
+    Brush::from(Color::from_argb_encoded(0xff00_0000 | rgb))
+}
+
+/// Cor por tipo
+pub fn type_color(t: PokemonType, palette: PaletteChoice) -> Brush {
+    let table = match palette {
+        PaletteChoice::Default => None,
+        PaletteChoice::Deuteranopia => Some(&DEUTERANOPIA_TYPES),
+        PaletteChoice::Protanopia => Some(&PROTANOPIA_TYPES),
+        PaletteChoice::Tritanopia => Some(&TRITANOPIA_TYPES),
+    };
+    if let Some(table) = table {
+        return hex(table[t as usize]);
+    }
+    let c = match t {
+        PokemonType::Normal => Color::from_rgb_u8(145, 154, 162),
+        PokemonType::Fire => Color::from_rgb_u8(255, 157, 85),
+        PokemonType::Water => Color::from_rgb_u8(80, 144, 214),
+        PokemonType::Electric => Color::from_rgb_u8(244, 210, 60),
+        PokemonType::Grass => Color::from_rgb_u8(99, 188, 90),
+        PokemonType::Ice => Color::from_rgb_u8(115, 206, 192),
+        PokemonType::Fighting => Color::from_rgb_u8(206, 65, 107),
+        PokemonType::Poison => Color::from_rgb_u8(170, 107, 200),
+        PokemonType::Ground => Color::from_rgb_u8(217, 120, 69),
+        PokemonType::Flying => Color::from_rgb_u8(143, 169, 222),
+        PokemonType::Psychic => Color::from_rgb_u8(250, 113, 121),
+        PokemonType::Bug => Color::from_rgb_u8(145, 193, 47),
+        PokemonType::Rock => Color::from_rgb_u8(197, 183, 140),
+        PokemonType::Ghost => Color::from_rgb_u8(82, 105, 173),
+        PokemonType::Dragon => Color::from_rgb_u8(11, 109, 195),
+        PokemonType::Dark => Color::from_rgb_u8(90, 84, 101),
+        PokemonType::Steel => Color::from_rgb_u8(90, 142, 162),
+        PokemonType::Fairy => Color::from_rgb_u8(236, 143, 230),
+    };
+    Brush::from(c)
+}
+
+fn load_embedded_image(bytes: &[u8]) -> slint::Image {
+    load_sprite(bytes).unwrap_or_default()
+}
+
+thread_local! {
+    // Todos os ícones de tipo decodificados uma única vez, no primeiro uso.
+    // `slint::Image` não é `Send`, então o registro é por thread (só a da UI usa).
+    static TYPE_ICON_REGISTRY: HashMap<PokemonType, slint::Image> = PokemonType::ALL
+        .into_iter()
+        .filter_map(|t| {
+            let data = TypeIcons::get(&format!("{}.png", t.id()))?.data;
+            Some((t, load_embedded_image(data.as_ref())))
+        })
+        .collect();
+}
+
+// ícone do tipo (ex.: Poison -> "poison.png"); clonar a imagem é barato
+pub fn type_icon(t: PokemonType) -> slint::Image {
+    TYPE_ICON_REGISTRY.with(|icons| icons.get(&t).cloned().unwrap_or_default()) // fallback
+}
+
+/// Cor por stat
+pub fn stat_color(k: Stat, palette: PaletteChoice) -> Brush {
+    let table = match palette {
+        PaletteChoice::Default => None,
+        PaletteChoice::Deuteranopia => Some(&DEUTERANOPIA_STATS),
+        PaletteChoice::Protanopia => Some(&PROTANOPIA_STATS),
+        PaletteChoice::Tritanopia => Some(&TRITANOPIA_STATS),
+    };
+    if let Some(table) = table {
+        return hex(table[k as usize]);
+    }
+    let c = match k {
+        Stat::Hp => Color::from_rgb_u8(105, 220, 18),
+        Stat::Attack => Color::from_rgb_u8(239, 204, 24),
+        Stat::Defense => Color::from_rgb_u8(232, 100, 18),
+        Stat::SpecialAttack => Color::from_rgb_u8(20, 195, 241),
+        Stat::SpecialDefense => Color::from_rgb_u8(74, 106, 223),
+        Stat::Speed => Color::from_rgb_u8(239, 99, 200),
+    };
+    Brush::from(c)
+}
+
+/// Cor pokemon
+pub fn pokemon_color(k: PokemonColor) -> Brush {
+    let c = match k {
+        PokemonColor::Black => Color::from_rgb_u8(43, 43, 43),
+        PokemonColor::Blue => Color::from_rgb_u8(0, 149, 217),
+        PokemonColor::Brown => Color::from_rgb_u8(150, 80, 66),
+        PokemonColor::Gray => Color::from_rgb_u8(125, 125, 125),
+        PokemonColor::Green => Color::from_rgb_u8(62, 179, 112),
+        PokemonColor::Pink => Color::from_rgb_u8(227, 134, 152),
+        PokemonColor::Purple => Color::from_rgb_u8(136, 72, 152),
+        PokemonColor::Red => Color::from_rgb_u8(230, 0, 51),
+        PokemonColor::White => Color::from_rgb_u8(255, 255, 255),
+        PokemonColor::Yellow => Color::from_rgb_u8(255, 217, 0),
+    };
+    Brush::from(c)
+}
+
+/// Carrega uma sprite em qualquer formato suportado (PNG, JPEG, GIF, WebP, SVG).
+/// Animações viram o primeiro quadro.
+pub fn load_sprite(bytes: &[u8]) -> Result<slint::Image, String> {
+    artwork::decode_scaled(bytes, 0).map(|a| a.to_image())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PALETTES: [PaletteChoice; 4] = [
+        PaletteChoice::Default,
+        PaletteChoice::Deuteranopia,
+        PaletteChoice::Protanopia,
+        PaletteChoice::Tritanopia,
+    ];
+
+    fn stat_colors(palette: PaletteChoice) -> Vec<Brush> {
+        Stat::ALL.map(|k| stat_color(k, palette)).to_vec()
+    }
+
+    #[test]
+    fn each_palette_has_its_own_stat_colors() {
+        for (i, a) in PALETTES.iter().enumerate() {
+            for b in &PALETTES[i + 1..] {
+                assert_ne!(stat_colors(*a), stat_colors(*b), "{a:?} e {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn stat_colors_are_distinct_within_a_palette() {
+        for palette in PALETTES {
+            let colors = stat_colors(palette);
+            for (i, c) in colors.iter().enumerate() {
+                assert!(
+                    !colors[i + 1..].contains(c),
+                    "{palette:?}: {:?}",
+                    Stat::ALL[i]
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use super::artwork::Artwork;
+use crate::{POKEMON_LIST, Pokemon, find_pokemon, search_pokemon};
+use serde::{Deserialize, Serialize};
+
+/// Sugestões mostradas enquanto digita o palpite
+const SUGGESTIONS: usize = 6;
+
+/// Placar do "Quem é esse Pokémon?", salvo nos ajustes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuizScore {
+    /// Acertos seguidos na sequência atual
+    pub streak: u32,
+    /// Maior sequência já feita
+    pub best_streak: u32,
+    pub correct: u32,
+    pub answered: u32,
+}
+
+impl QuizScore {
+    /// Conta um palpite (desistir conta como erro)
+    pub fn record(&mut self, correct: bool) {
+        self.answered += 1;
+        if correct {
+            self.correct += 1;
+            self.streak += 1;
+            self.best_streak = self.best_streak.max(self.streak);
+        } else {
+            self.streak = 0;
+        }
+    }
+}
+
+/// Rodada em andamento
+pub struct Round {
+    pub answer: Pokemon,
+    /// Artwork normal, mostrada ao revelar
+    pub artwork: Option<Artwork>,
+    pub revealed: bool,
+}
+
+/// Sorteia um Pokémon da geração (0 = todas), evitando repetir `last`
+pub fn pick(generation: u8, last: Option<u32>) -> Option<Pokemon> {
+    let pool: Vec<Pokemon> = POKEMON_LIST
+        .iter()
+        .filter(|p| generation == 0 || p.generation == generation)
+        .filter(|p| Some(p.id) != last)
+        .copied()
+        .collect();
+    if pool.is_empty() {
+        return None;
+    }
+    Some(pool[fastrand::usize(..pool.len())])
+}
+
+/// Sugestões da busca da lista para o texto digitado
+pub fn suggestions(text: &str) -> Vec<Pokemon> {
+    if text.trim().is_empty() {
+        return Vec::new();
+    }
+    let mut found = search_pokemon(text.trim());
+    found.truncate(SUGGESTIONS);
+    found
+}
+
+/// Palpite: nome/número exato ou, senão, a primeira sugestão
+pub fn resolve(text: &str) -> Option<Pokemon> {
+    find_pokemon(text).or_else(|| suggestions(text).into_iter().next())
+}
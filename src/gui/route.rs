@@ -0,0 +1,167 @@
+use crate::find_pokemon;
+
+/// Painel principal mostrado na janela
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Page {
+    #[default]
+    Pokedex,
+    Team,
+    Quiz,
+    Drill,
+    Settings,
+}
+
+/// Tela aberta, no formato do hash da URL (`#/pokemon/25`, `#/search/char`...)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Route {
+    /// Lista completa, sem seleção
+    #[default]
+    Home,
+    Pokemon(u32),
+    Search(String),
+    Team,
+    Quiz,
+    Drill,
+    Settings,
+}
+
+impl Route {
+    /// Lê um hash com ou sem `#` ("#/pokemon/25"); rotas desconhecidas dão `None`
+    #[cfg(any(target_arch = "wasm32", test))]
+    pub fn parse(hash: &str) -> Option<Route> {
+        let path = hash.trim_start_matches('#').trim_start_matches('/');
+        let (head, rest) = path.split_once('/').unwrap_or((path, ""));
+        match head {
+            "" => Some(Route::Home),
+            "pokemon" => find_pokemon(&decode(rest)).map(|p| Route::Pokemon(p.id)),
+            "search" => {
+                let query = decode(rest);
+                Some(if query.is_empty() {
+                    Route::Home
+                } else {
+                    Route::Search(query)
+                })
+            }
+            "team" => Some(Route::Team),
+            "quiz" => Some(Route::Quiz),
+            "drill" => Some(Route::Drill),
+            "settings" => Some(Route::Settings),
+            _ => None,
+        }
+    }
+
+    /// Hash sem o `#` ("/pokemon/25")
+    #[cfg(any(target_arch = "wasm32", test))]
+    pub fn to_hash(&self) -> String {
+        match self {
+            Route::Home => "/".into(),
+            Route::Pokemon(id) => format!("/pokemon/{id}"),
+            Route::Search(query) => format!("/search/{}", encode(query)),
+            Route::Team => "/team".into(),
+            Route::Quiz => "/quiz".into(),
+            Route::Drill => "/drill".into(),
+            Route::Settings => "/settings".into(),
+        }
+    }
+
+    /// Argumentos do desktop: `slindex_app 25`, `slindex_app pikachu` ou `--pokemon pikachu`
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_args(args: &[String]) -> Result<Option<Route>, String> {
+        let query = match args {
+            [] => return Ok(None),
+            [flag, value] if flag == "--pokemon" => value.as_str(),
+            [arg] => match arg.strip_prefix("--pokemon=") {
+                Some(value) => value,
+                None if arg.starts_with('-') => return Err(format!("opção desconhecida: {arg}")),
+                None => arg.as_str(),
+            },
+            _ => return Err(format!("argumentos inválidos: {}", args.join(" "))),
+        };
+        find_pokemon(query)
+            .map(|p| Some(Route::Pokemon(p.id)))
+            .ok_or_else(|| format!("Pokémon não encontrado: {query}"))
+    }
+}
+
+/// Codifica em percent-encoding tudo que não for letra, número ou `-_.~:`
+#[cfg(any(target_arch = "wasm32", test))]
+fn encode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~:".contains(&byte) {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    out
+}
+
+/// Desfaz o percent-encoding; sequências inválidas ficam como estão
+#[cfg(any(target_arch = "wasm32", test))]
+fn decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                out.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                out.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PokemonType, search_pokemon};
+
+    #[test]
+    fn type_search_round_trips() {
+        let route = Route::parse("#/search/type:fire").unwrap();
+        assert_eq!(route, Route::Search("type:fire".into()));
+        assert_eq!(route.to_hash(), "/search/type:fire");
+
+        let Route::Search(query) = route else {
+            unreachable!()
+        };
+        let found = search_pokemon(&query);
+        assert!(found.iter().any(|p| p.name == "Charmander"));
+        assert!(found.iter().all(|p| p.types.contains(&PokemonType::Fire)));
+    }
+
+    #[test]
+    fn type_search_mixes_with_name() {
+        let found = search_pokemon("tipo:fogo char");
+        let names: Vec<&str> = found.iter().map(|p| p.name).collect();
+        assert_eq!(
+            names,
+            [
+                "Charmander",
+                "Charmeleon",
+                "Charizard",
+                "Chimchar",
+                "Charcadet"
+            ]
+        );
+        assert!(search_pokemon("type:nope").is_empty());
+    }
+
+    #[test]
+    fn pokemon_route_round_trips() {
+        let route = Route::parse("#/pokemon/pikachu").unwrap();
+        assert_eq!(route, Route::Pokemon(25));
+        assert_eq!(Route::parse(&route.to_hash()), Some(route));
+    }
+}
@@ -0,0 +1,227 @@
+use super::{artwork, drill::DrillStats, quiz::QuizScore};
+use crate::{
+    helpers::{Language, Units},
+    pokedex,
+};
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroUsize;
+
+/// Tema escolhido nos ajustes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeChoice {
+    /// Segue o claro/escuro do sistema
+    #[default]
+    System,
+    Light,
+    Dark,
+    HighContrast,
+}
+
+/// Paleta de tipos e stats (alternativas para daltonismo)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PaletteChoice {
+    #[default]
+    Default,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+}
+
+/// Onde o usuário parou (reaberto no próximo início)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+    /// Texto da busca
+    pub search: String,
+    /// Pokémon aberto no detalhe
+    pub pokemon: Option<u32>,
+}
+
+/// Tamanho e posição da janela, em pixels físicos (só desktop)
+#[cfg(not(any(target_arch = "wasm32", target_os = "android")))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowGeometry {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub maximized: bool,
+}
+
+/// Limites dos caches em memória
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CacheLimits {
+    /// Detalhes guardados da PokeAPI
+    pub details: usize,
+    /// Artworks decodificadas, em MB
+    pub artwork_mb: usize,
+}
+
+impl CacheLimits {
+    pub const DETAILS: std::ops::RangeInclusive<usize> = 1..=500;
+    pub const ARTWORK_MB: std::ops::RangeInclusive<usize> = 4..=512;
+
+    /// Traz valores editados à mão no arquivo para a faixa aceita
+    pub fn clamped(self) -> Self {
+        Self {
+            details: self
+                .details
+                .clamp(*Self::DETAILS.start(), *Self::DETAILS.end()),
+            artwork_mb: self
+                .artwork_mb
+                .clamp(*Self::ARTWORK_MB.start(), *Self::ARTWORK_MB.end()),
+        }
+    }
+
+    pub fn details(self) -> NonZeroUsize {
+        NonZeroUsize::new(self.clamped().details).unwrap_or(NonZeroUsize::MIN)
+    }
+
+    pub fn artwork_bytes(self) -> usize {
+        self.clamped().artwork_mb * 1024 * 1024
+    }
+}
+
+impl Default for CacheLimits {
+    fn default() -> Self {
+        Self {
+            details: pokedex::DEFAULT_CACHE,
+            artwork_mb: artwork::DEFAULT_BUDGET_BYTES / (1024 * 1024),
+        }
+    }
+}
+
+/// Preferências salvas entre sessões
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub language: Language,
+    pub theme: ThemeChoice,
+    pub palette: PaletteChoice,
+    /// Lista só com número e nome, sem sprites nem tipos
+    pub text_rows: bool,
+    /// Cartões em grade no lugar da lista
+    pub grid_view: bool,
+    /// Unidades de altura e peso
+    pub units: Units,
+    pub cache: CacheLimits,
+    pub session: Session,
+    #[cfg(not(any(target_arch = "wasm32", target_os = "android")))]
+    pub window: Option<WindowGeometry>,
+    pub quiz: QuizScore,
+    pub drill: DrillStats,
+}
+
+/// Onde os ajustes ficam guardados; nos testes, um em memória
+pub trait SettingsStore: Clone + Send + 'static {
+    /// Sem nada salvo (ou ilegível) devolve o padrão
+    fn load(&self) -> Settings;
+    /// Falhas só vão para o log, a sessão continua normalmente
+    fn save(&self, settings: &Settings);
+}
+
+/// Armazenamento da plataforma: arquivo no desktop/Android, `localStorage` na web
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlatformSettings;
+
+impl SettingsStore for PlatformSettings {
+    fn load(&self) -> Settings {
+        storage::read()
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, settings: &Settings) {
+        let result = serde_json::to_string_pretty(settings)
+            .map_err(|e| e.to_string())
+            .and_then(|text| storage::write(&text));
+        if let Err(e) = result {
+            eprintln!("falha ao salvar ajustes: {e}");
+        }
+    }
+}
+
+#[cfg(target_os = "android")]
+pub use storage::set_data_dir;
+
+// =================== Desktop / Android ===================
+#[cfg(not(target_arch = "wasm32"))]
+mod storage {
+    use std::{path::PathBuf, sync::OnceLock};
+
+    const FILE: &str = "settings.json";
+
+    /// Pasta de dados definida pela plataforma (Android); senão, a de configuração do SO
+    static DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+    #[cfg(target_os = "android")]
+    pub fn set_data_dir(dir: PathBuf) {
+        DATA_DIR.set(dir).ok();
+    }
+
+    fn path() -> Option<PathBuf> {
+        DATA_DIR
+            .get()
+            .cloned()
+            .or_else(|| dirs::config_dir().map(|dir| dir.join("slindex")))
+            .map(|dir| dir.join(FILE))
+    }
+
+    pub fn read() -> Option<String> {
+        std::fs::read_to_string(path()?).ok()
+    }
+
+    pub fn write(text: &str) -> Result<(), String> {
+        let path = path().ok_or("pasta de configuração desconhecida")?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+        }
+        std::fs::write(&path, text).map_err(|e| format!("{}: {e}", path.display()))
+    }
+}
+
+// =================== WebAssembly ===================
+#[cfg(target_arch = "wasm32")]
+mod storage {
+    const KEY: &str = "slindex.settings";
+
+    fn local_storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok()?
+    }
+
+    pub fn read() -> Option<String> {
+        local_storage()?.get_item(KEY).ok()?
+    }
+
+    pub fn write(text: &str) -> Result<(), String> {
+        let storage = local_storage().ok_or("localStorage indisponível")?;
+        storage.set_item(KEY, text).map_err(|e| format!("{e:?}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn language_defaults_to_pt_br() {
+        let settings: Settings = serde_json::from_str("{}").unwrap();
+        assert_eq!(settings.language, Language::PtBr);
+    }
+
+    #[test]
+    fn language_round_trips() {
+        let settings = Settings {
+            language: Language::EnUs,
+            ..Settings::default()
+        };
+        let text = serde_json::to_string(&settings).unwrap();
+        assert!(text.contains(r#""language":"en-US""#), "{text}");
+        let back: Settings = serde_json::from_str(&text).unwrap();
+        assert_eq!(back, settings);
+    }
+}
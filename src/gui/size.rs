@@ -0,0 +1,150 @@
+use super::artwork::Artwork;
+use crate::{Pokemon, helpers::Language};
+
+/// Altura da silhueta humana de referência, em decímetros (como na PokeAPI)
+pub const HUMAN_HEIGHT: u32 = 17;
+
+/// Menor fração do palco que ainda dá para ver; abaixo disso a figura é ampliada
+/// (Joltik, 1 dm, ao lado de Eternatus, 200 dm, ficaria com 1/200 da altura)
+const MIN_FRACTION: f32 = 0.05;
+
+/// Outro Pokémon escolhido para a comparação
+#[derive(Clone)]
+pub struct SizeReference {
+    pub pokemon: Pokemon,
+    /// Altura em decímetros; 0 enquanto o detalhe carrega
+    pub height: u32,
+    pub artwork: Option<Artwork>,
+}
+
+/// Uma figura no palco
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Figure {
+    /// Altura desenhada, como fração da altura do palco (0..=1)
+    pub fraction: f32,
+    /// Quantas vezes foi ampliada para continuar visível (1 = em escala)
+    pub zoom: f32,
+}
+
+impl Figure {
+    fn scaled(fraction: f32) -> Self {
+        if fraction < MIN_FRACTION {
+            Self {
+                fraction: MIN_FRACTION,
+                zoom: MIN_FRACTION / fraction,
+            }
+        } else {
+            Self {
+                fraction,
+                zoom: 1.0,
+            }
+        }
+    }
+}
+
+/// Duas figuras na mesma escala: a mais alta ocupa o palco inteiro
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SizeComparison {
+    pub subject: Figure,
+    pub reference: Figure,
+    /// Altura do Pokémon dividida pela da referência
+    pub ratio: f32,
+}
+
+/// Compara duas alturas em decímetros; `None` se alguma for desconhecida (0)
+pub fn compare(subject: u32, reference: u32) -> Option<SizeComparison> {
+    if subject == 0 || reference == 0 {
+        return None;
+    }
+    let tallest = subject.max(reference) as f32;
+    Some(SizeComparison {
+        subject: Figure::scaled(subject as f32 / tallest),
+        reference: Figure::scaled(reference as f32 / tallest),
+        ratio: subject as f32 / reference as f32,
+    })
+}
+
+/// Multiplicador (≥ 1) com o separador decimal do idioma: "3,5×" ou "3.5×", "59×"
+pub fn times(value: f32, language: Language) -> String {
+    if value >= 10.0 {
+        return format!("{value:.0}×");
+    }
+    let (decimal, _) = language.separators();
+    format!(
+        "{}×",
+        format!("{value:.1}").replace('.', &decimal.to_string())
+    )
+}
+
+/// Frase da comparação, sempre com multiplicador ≥ 1
+/// ("Wailord é 8.5× mais alto que uma pessoa", "Joltik cabe 200× na altura de Eternatus")
+pub fn summary(subject: &str, reference: &str, ratio: f32, language: Language) -> String {
+    let factor = ratio.max(1.0 / ratio);
+    let times = times(factor, language);
+    // igual na casa decimal mostrada
+    if (factor * 10.0).round() == 10.0 {
+        format!("{subject} tem a mesma altura que {reference}")
+    } else if ratio > 1.0 {
+        format!("{subject} é {times} mais alto que {reference}")
+    } else {
+        format!("{subject} cabe {times} na altura de {reference}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extremes_are_zoomed_to_stay_visible() {
+        // Joltik (1 dm) ao lado de Eternatus (200 dm)
+        let c = compare(1, 200).unwrap();
+        assert_eq!(
+            c.reference,
+            Figure {
+                fraction: 1.0,
+                zoom: 1.0
+            }
+        );
+        assert_eq!(c.subject.fraction, MIN_FRACTION);
+        assert!((c.subject.zoom - 10.0).abs() < 1e-4, "{}", c.subject.zoom);
+        assert_eq!(
+            summary("Joltik", "Eternatus", c.ratio, Language::PtBr),
+            "Joltik cabe 200× na altura de Eternatus"
+        );
+        let c = compare(200, 1).unwrap();
+        assert_eq!(
+            summary("Eternatus", "Joltik", c.ratio, Language::PtBr),
+            "Eternatus é 200× mais alto que Joltik"
+        );
+    }
+
+    #[test]
+    fn equal_heights() {
+        let c = compare(4, 4).unwrap();
+        assert_eq!(c.subject, c.reference);
+        assert_eq!(c.ratio, 1.0);
+        assert_eq!(
+            summary("Pikachu", "Pichu", c.ratio, Language::PtBr),
+            "Pikachu tem a mesma altura que Pichu"
+        );
+    }
+
+    #[test]
+    fn unknown_height_has_no_comparison() {
+        assert_eq!(compare(0, 17), None);
+        assert_eq!(compare(17, 0), None);
+    }
+
+    #[test]
+    fn multiplier_follows_language() {
+        assert_eq!(times(3.5, Language::PtBr), "3,5×");
+        assert_eq!(times(3.5, Language::EnUs), "3.5×");
+        assert_eq!(times(59.4, Language::EnUs), "59×");
+        let c = compare(145, 17).unwrap();
+        assert_eq!(
+            summary("Wailord", "uma pessoa", c.ratio, Language::EnUs),
+            "Wailord é 8.5× mais alto que uma pessoa"
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use super::executor::MaybeSend;
+use crate::{
+    Pokedex,
+    service::{Detail, SpriteVariant},
+};
+use std::{future::Future, num::NonZeroUsize};
+
+/// De onde o controller tira detalhes e imagens: a PokeAPI (via `Pokedex`)
+/// ou, nos testes, dados fixos sem rede
+pub trait DataSource: Clone + MaybeSend + 'static {
+    /// Detalhe já em cache, sem acessar a rede
+    fn cached_detail(&self, id: u32) -> Option<Detail>;
+    fn detail(&self, id: u32) -> impl Future<Output = Result<Detail, String>> + MaybeSend;
+    /// Bytes da artwork na variante pedida
+    fn artwork_variant(
+        &self,
+        id: u32,
+        variant: SpriteVariant,
+    ) -> impl Future<Output = Result<Vec<u8>, String>> + MaybeSend;
+    /// Bytes da sprite pequena das listas
+    fn sprite(&self, id: u32) -> impl Future<Output = Result<Vec<u8>, String>> + MaybeSend;
+    fn set_cache_size(&self, cap: NonZeroUsize);
+}
+
+impl DataSource for Pokedex {
+    fn cached_detail(&self, id: u32) -> Option<Detail> {
+        Pokedex::cached_detail(self, id)
+    }
+
+    fn detail(&self, id: u32) -> impl Future<Output = Result<Detail, String>> + MaybeSend {
+        Pokedex::detail(self, id)
+    }
+
+    fn artwork_variant(
+        &self,
+        id: u32,
+        variant: SpriteVariant,
+    ) -> impl Future<Output = Result<Vec<u8>, String>> + MaybeSend {
+        Pokedex::artwork_variant(self, id, variant)
+    }
+
+    fn sprite(&self, id: u32) -> impl Future<Output = Result<Vec<u8>, String>> + MaybeSend {
+        Pokedex::sprite(self, id)
+    }
+
+    fn set_cache_size(&self, cap: NonZeroUsize) {
+        Pokedex::set_cache_size(self, cap);
+    }
+}
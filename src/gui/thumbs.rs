@@ -0,0 +1,68 @@
+use super::artwork::{Artwork, ArtworkCache};
+use std::collections::HashSet;
+
+/// Lado das miniaturas, em pixels: o tamanho original das sprites (a grade mostra 96 px lógicos)
+pub const THUMB_SIZE: u32 = 96;
+
+/// Miniaturas decodificadas guardadas (cerca de 36 KB cada)
+const BUDGET_BYTES: usize = 8 * 1024 * 1024;
+
+/// Downloads simultâneos; o resto espera na fila
+const PARALLEL: usize = 4;
+
+/// Pedidos guardados na fila; ao rolar rápido, os mais antigos saem
+const QUEUE: usize = 48;
+
+/// Miniaturas da lista e da grade: cache e fila de downloads.
+/// A fila é uma pilha: a linha que apareceu por último carrega primeiro.
+pub struct Thumbs {
+    cache: ArtworkCache<u32>,
+    queue: Vec<u32>,
+    loading: HashSet<u32>,
+}
+
+impl Default for Thumbs {
+    fn default() -> Self {
+        Self {
+            cache: ArtworkCache::new(BUDGET_BYTES),
+            queue: Vec::new(),
+            loading: HashSet::new(),
+        }
+    }
+}
+
+impl Thumbs {
+    pub fn get(&mut self, id: u32) -> Option<Artwork> {
+        self.cache.get(&id)
+    }
+
+    /// Põe na fila (ou sobe para o topo) se ainda não estiver baixando
+    pub fn request(&mut self, id: u32) {
+        if self.loading.contains(&id) {
+            return;
+        }
+        self.queue.retain(|queued| *queued != id);
+        self.queue.push(id);
+        if self.queue.len() > QUEUE {
+            self.queue.remove(0);
+        }
+    }
+
+    /// Próximo download a começar, se houver vaga
+    pub fn next(&mut self) -> Option<u32> {
+        if self.loading.len() >= PARALLEL {
+            return None;
+        }
+        let id = self.queue.pop()?;
+        self.loading.insert(id);
+        Some(id)
+    }
+
+    /// Fim de um download; sem imagem, a linha pede de novo quando reaparecer
+    pub fn done(&mut self, id: u32, thumb: Option<Artwork>) {
+        self.loading.remove(&id);
+        if let Some(thumb) = thumb {
+            self.cache.put(id, thumb);
+        }
+    }
+}
@@ -1,116 +1,52 @@
-use rust_embed::Embed;
-use slint::{Brush, Color};
-
-// ÍCONES DE TIPOS
-#[derive(Embed)]
-#[folder = "imagens/tipos/"] // embute toda a pasta
-struct TypeIcons;
-
-/// Cor por tipo
-pub fn type_color(t: &str) -> Brush {
-    let c = match t {
-        "normal" => Color::from_rgb_u8(145, 154, 162),
-        "fire" => Color::from_rgb_u8(255, 157, 85),
-        "water" => Color::from_rgb_u8(80, 144, 214),
-        "electric" => Color::from_rgb_u8(244, 210, 60),
-        "grass" => Color::from_rgb_u8(99, 188, 90),
-        "ice" => Color::from_rgb_u8(115, 206, 192),
-        "fighting" => Color::from_rgb_u8(206, 65, 107),
-        "poison" => Color::from_rgb_u8(170, 107, 200),
-        "ground" => Color::from_rgb_u8(217, 120, 69),
-        "flying" => Color::from_rgb_u8(143, 169, 222),
-        "psychic" => Color::from_rgb_u8(250, 113, 121),
-        "bug" => Color::from_rgb_u8(145, 193, 47),
-        "rock" => Color::from_rgb_u8(197, 183, 140),
-        "ghost" => Color::from_rgb_u8(82, 105, 173),
-        "dragon" => Color::from_rgb_u8(11, 109, 195),
-        "dark" => Color::from_rgb_u8(90, 84, 101),
-        "steel" => Color::from_rgb_u8(90, 142, 162),
-        "fairy" => Color::from_rgb_u8(236, 143, 230),
-        _ => Color::from_rgb_u8(145, 154, 162),
-    };
-    Brush::from(c)
-}
+use crate::types::{PokemonType, Stat};
+use serde::{Deserialize, Serialize};
 
 /// Rótulo PT-BR dos tipos
-pub fn type_label_pt(t: &str) -> &'static str {
+pub fn type_label_pt(t: PokemonType) -> &'static str {
     match t {
-        "normal" => "Normal",
-        "fire" => "Fogo",
-        "water" => "Água",
-        "electric" => "Elétrico",
-        "grass" => "Grama",
-        "ice" => "Gelo",
-        "fighting" => "Lutador",
-        "poison" => "Venenoso",
-        "ground" => "Terrestre",
-        "flying" => "Voador",
-        "psychic" => "Psíquico",
-        "bug" => "Inseto",
-        "rock" => "Pedra",
-        "ghost" => "Fantasma",
-        "dragon" => "Dragão",
-        "dark" => "Noturno",
-        "steel" => "Aço",
-        "fairy" => "Fada",
-        _ => "Desconhecido",
+        PokemonType::Normal => "Normal",
+        PokemonType::Fire => "Fogo",
+        PokemonType::Water => "Água",
+        PokemonType::Electric => "Elétrico",
+        PokemonType::Grass => "Grama",
+        PokemonType::Ice => "Gelo",
+        PokemonType::Fighting => "Lutador",
+        PokemonType::Poison => "Venenoso",
+        PokemonType::Ground => "Terrestre",
+        PokemonType::Flying => "Voador",
+        PokemonType::Psychic => "Psíquico",
+        PokemonType::Bug => "Inseto",
+        PokemonType::Rock => "Pedra",
+        PokemonType::Ghost => "Fantasma",
+        PokemonType::Dragon => "Dragão",
+        PokemonType::Dark => "Noturno",
+        PokemonType::Steel => "Aço",
+        PokemonType::Fairy => "Fada",
     }
 }
 
-fn load_embedded_image(bytes: &[u8]) -> slint::Image {
-    // usa seu png_to_image; se quiser suportar .webp também, o `image` já lida
-    png_to_image(bytes).unwrap_or_default()
-}
-
-// carrega um ícone de tipo pelo nome (ex.: "poison" -> "poison.png")
-pub fn type_icon(t: &str) -> slint::Image {
-    if let Some(embeded_file) = TypeIcons::get(&format!("{t}.png")) {
-        load_embedded_image(embeded_file.data.as_ref())
-    } else {
-        slint::Image::default() // fallback
+/// Rótulo PT-BR dos stats
+pub fn stat_label_pt(k: Stat) -> &'static str {
+    match k {
+        Stat::Hp => "Pontos de Vida",
+        Stat::Attack => "Ataque",
+        Stat::Defense => "Defesa",
+        Stat::SpecialAttack => "Ataque Especial",
+        Stat::SpecialDefense => "Defesa Especial",
+        Stat::Speed => "Velocidade",
     }
 }
 
-/// Cor por stat
-pub fn stat_color(k: &str) -> Brush {
-    let c = match k {
-        "hp" => Color::from_rgb_u8(105, 220, 18),
-        "attack" => Color::from_rgb_u8(239, 204, 24),
-        "defense" => Color::from_rgb_u8(232, 100, 18),
-        "special-attack" => Color::from_rgb_u8(20, 195, 241),
-        "special-defense" => Color::from_rgb_u8(74, 106, 223),
-        "speed" => Color::from_rgb_u8(239, 99, 200),
-        _ => Color::from_rgb_u8(213, 29, 173),
-    };
-    Brush::from(c)
-}
-
-/// Cor pokemon
-pub fn pokemon_color(k: &str) -> Brush {
-    let c = match k {
-        "1" => Color::from_rgb_u8(43, 43, 43),    // Black
-        "2" => Color::from_rgb_u8(0, 149, 217),   // Blue
-        "3" => Color::from_rgb_u8(150, 80, 66),   // Brown
-        "4" => Color::from_rgb_u8(125, 125, 125), // Gray
-        "5" => Color::from_rgb_u8(62, 179, 112),  // Green
-        "6" => Color::from_rgb_u8(227, 134, 152), // Pink
-        "7" => Color::from_rgb_u8(136, 72, 152),  // Purple
-        "8" => Color::from_rgb_u8(230, 0, 51),    // Red
-        "9" => Color::from_rgb_u8(255, 255, 255), // White
-        "10" => Color::from_rgb_u8(255, 217, 0),  // Yellow
-        _ => Color::from_rgb_u8(0, 0, 0),         // Default: Black
-    };
-    Brush::from(c)
-}
-
-/// Converte bytes PNG -> Image
-pub fn png_to_image(bytes: &[u8]) -> Result<slint::Image, String> {
-    let img = image::load_from_memory(bytes).map_err(|e| e.to_string())?;
-    let rgba = img.to_rgba8();
-    let (w, h) = rgba.dimensions();
-    let mut buf = slint::SharedPixelBuffer::<slint::Rgba8Pixel>::new(w, h);
-    buf.make_mut_bytes().copy_from_slice(rgba.as_raw());
-    Ok(slint::Image::from_rgba8(buf))
+/// Abreviação PT-BR dos stats (aparece nas barras)
+pub fn stat_abbrev_pt(k: Stat) -> &'static str {
+    match k {
+        Stat::Hp => "PV",
+        Stat::Attack => "Atq",
+        Stat::Defense => "Def",
+        Stat::SpecialAttack => "AtqE",
+        Stat::SpecialDefense => "DefE",
+        Stat::Speed => "Vel",
+    }
 }
 
 /// Capitaliza palavras e substitui hífens por espaço
@@ -133,3 +69,148 @@ pub fn cap_words_and_spaces(s: &str) -> String {
     }
     out
 }
+
+/// Região de cada geração (índice = geração - 1)
+pub const REGIONS: [&str; 9] = [
+    "Kanto", "Johto", "Hoenn", "Sinnoh", "Unova", "Kalos", "Alola", "Galar", "Paldea",
+];
+
+/// Número romano da geração ("III")
+pub fn generation_roman(generation: u8) -> &'static str {
+    const ROMAN: [&str; 9] = ["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX"];
+    ROMAN
+        .get(usize::from(generation).wrapping_sub(1))
+        .copied()
+        .unwrap_or("?")
+}
+
+/// Selo da geração com a região ("Gen III · Hoenn")
+pub fn generation_label(generation: u8) -> String {
+    match REGIONS.get(usize::from(generation).wrapping_sub(1)) {
+        Some(region) => format!("Gen {} · {region}", generation_roman(generation)),
+        None => format!("Gen {generation}"),
+    }
+}
+
+// =================== Idioma ===================
+/// Idioma do usuário, salvo nos ajustes (a interface hoje é só em português)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    #[serde(rename = "pt-BR")]
+    PtBr,
+    #[serde(rename = "en-US")]
+    EnUs,
+}
+
+impl Language {
+    /// Separadores decimal e de milhar ("1.234,5" ou "1,234.5")
+    pub fn separators(self) -> (char, char) {
+        match self {
+            Language::PtBr => (',', '.'),
+            Language::EnUs => ('.', ','),
+        }
+    }
+}
+
+// =================== Unidades ===================
+/// Sistema de unidades para altura e peso
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Units {
+    /// Metros e quilos ("0,7 m")
+    #[default]
+    Metric,
+    /// Pés/polegadas e libras ("2′04″", "15,2 lbs")
+    Imperial,
+    /// Métrico com o imperial entre parênteses
+    Both,
+}
+
+/// Número em décimos com uma casa decimal ("1.234,5")
+fn tenths(value: u64, (decimal, group): (char, char)) -> String {
+    let int = (value / 10).to_string();
+    let mut out = String::with_capacity(int.len() + 4);
+    for (i, ch) in int.chars().enumerate() {
+        if i > 0 && (int.len() - i).is_multiple_of(3) {
+            out.push(group);
+        }
+        out.push(ch);
+    }
+    out.push(decimal);
+    out.push_str(&(value % 10).to_string());
+    out
+}
+
+/// Altura em decímetros (como vem da PokeAPI): "0,7 m", "2′04″" ou "0,7 m (2′04″)";
+/// os separadores seguem o idioma, não o sistema de unidades
+pub fn format_height(decimetres: u32, units: Units, language: Language) -> String {
+    let metric = || format!("{} m", tenths(decimetres.into(), language.separators()));
+    let imperial = || {
+        let inches = (f64::from(decimetres) * 3.937_007_874).round() as u64;
+        format!("{}′{:02}″", inches / 12, inches % 12)
+    };
+    match units {
+        Units::Metric => metric(),
+        Units::Imperial => imperial(),
+        Units::Both => format!("{} ({})", metric(), imperial()),
+    }
+}
+
+/// Peso em hectogramas (como vem da PokeAPI): "6,9 kg", "15,2 lbs" ou "6,9 kg (15,2 lbs)"
+pub fn format_weight(hectograms: u32, units: Units, language: Language) -> String {
+    let separators = language.separators();
+    let metric = || format!("{} kg", tenths(hectograms.into(), separators));
+    let imperial = || {
+        let lbs = (f64::from(hectograms) * 2.204_622_62).round() as u64;
+        format!("{} lbs", tenths(lbs, separators))
+    };
+    match units {
+        Units::Metric => metric(),
+        Units::Imperial => imperial(),
+        Units::Both => format!("{} ({})", metric(), imperial()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn height_in_every_locale_and_unit() {
+        // Pikachu: 4 dm
+        assert_eq!(format_height(4, Units::Metric, Language::PtBr), "0,4 m");
+        assert_eq!(format_height(4, Units::Metric, Language::EnUs), "0.4 m");
+        assert_eq!(format_height(4, Units::Imperial, Language::PtBr), "1′04″");
+        assert_eq!(format_height(4, Units::Imperial, Language::EnUs), "1′04″");
+        assert_eq!(
+            format_height(4, Units::Both, Language::PtBr),
+            "0,4 m (1′04″)"
+        );
+    }
+
+    #[test]
+    fn weight_in_every_locale_and_unit() {
+        // Snorlax: 4600 hg
+        assert_eq!(
+            format_weight(4600, Units::Metric, Language::PtBr),
+            "460,0 kg"
+        );
+        assert_eq!(
+            format_weight(4600, Units::Metric, Language::EnUs),
+            "460.0 kg"
+        );
+        assert_eq!(
+            format_weight(4600, Units::Imperial, Language::PtBr),
+            "1.014,1 lbs"
+        );
+        assert_eq!(
+            format_weight(4600, Units::Imperial, Language::EnUs),
+            "1,014.1 lbs"
+        );
+        assert_eq!(
+            format_weight(60, Units::Both, Language::EnUs),
+            "6.0 kg (13.2 lbs)"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{
+    POKEMON_LIST, Pokemon, PokemonType,
+    helpers::type_label_pt,
+    service::{self, Detail, PokemonService, SpriteVariant},
+};
+use lru::LruCache;
+use std::{
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+};
+
+/// Quantos detalhes ficam em cache por padrão
+pub const DEFAULT_CACHE: usize = 50;
+
+type Cache<K, V> = Arc<Mutex<LruCache<K, V>>>;
+
+/// Acesso à Pokédex sem UI: lista embutida + PokeAPI com cache
+#[derive(Clone)]
+pub struct Pokedex {
+    service: PokemonService,
+    details: Cache<u32, Detail>,
+}
+
+impl Default for Pokedex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pokedex {
+    pub fn new() -> Self {
+        Self::with_cache_size(NonZeroUsize::new(DEFAULT_CACHE).unwrap())
+    }
+
+    /// Define quantos detalhes ficam em cache
+    pub fn with_cache_size(cap: NonZeroUsize) -> Self {
+        Self {
+            service: PokemonService::new(),
+            details: Arc::new(Mutex::new(LruCache::new(cap))),
+        }
+    }
+
+    /// Muda o tamanho do cache de detalhes; o excedente sai pelo LRU
+    pub fn set_cache_size(&self, cap: NonZeroUsize) {
+        self.details.lock().unwrap().resize(cap);
+    }
+
+    /// Todos os Pokémon embutidos, em ordem de número
+    pub fn list(&self) -> &'static [Pokemon] {
+        POKEMON_LIST
+    }
+
+    /// Busca por número ou nome
+    pub fn search(&self, filter: &str) -> Vec<Pokemon> {
+        search_pokemon(filter)
+    }
+
+    /// Pokémon pelo número ou nome exato
+    pub fn find(&self, query: &str) -> Option<Pokemon> {
+        find_pokemon(query)
+    }
+
+    /// Detalhes da PokeAPI (tipos, stats, habilidades...)
+    pub async fn detail(&self, id: u32) -> Result<Detail, String> {
+        if let Some(detail) = self.cached_detail(id) {
+            return Ok(detail);
+        }
+        let detail = self.service.fetch_pokemon_detail(id).await?;
+        self.details.lock().unwrap().put(id, detail.clone());
+        Ok(detail)
+    }
+
+    /// Detalhes já em cache, sem acessar a rede
+    pub fn cached_detail(&self, id: u32) -> Option<Detail> {
+        self.details.lock().unwrap().get(&id).cloned()
+    }
+
+    /// Bytes (PNG) da official artwork
+    pub async fn artwork(&self, id: u32) -> Result<Vec<u8>, String> {
+        self.artwork_variant(id, SpriteVariant::default()).await
+    }
+
+    /// Bytes (PNG) da sprite pequena, para listas; não entra no cache
+    pub async fn sprite(&self, id: u32) -> Result<Vec<u8>, String> {
+        self.service.fetch_image(&service::sprite_url(id)).await
+    }
+
+    /// Bytes da artwork na variante pedida (shiny/fêmea), com fallback. Não entra no
+    /// cache: quem exibe guarda a imagem já decodificada (ver `gui::artwork::ArtworkCache`)
+    pub async fn artwork_variant(
+        &self,
+        id: u32,
+        variant: SpriteVariant,
+    ) -> Result<Vec<u8>, String> {
+        let detail = self.detail(id).await?;
+        let url = detail
+            .sprites
+            .artwork_url(variant)
+            .ok_or_else(|| format!("Pokémon {id} sem artwork"))?;
+        self.service.fetch_image(url).await
+    }
+}
+
+/// Filtra a lista por id ou nome (sem diferenciar maiúsculas). `type:fire` (ou `tipo:fogo`)
+/// restringe ao tipo; o resto do texto continua buscando por id ou nome.
+pub fn search_pokemon(filter: &str) -> Vec<Pokemon> {
+    let (types, words): (Vec<&str>, Vec<&str>) = filter
+        .split_whitespace()
+        .partition(|word| type_filter(word).is_some());
+    let mut wanted = Vec::with_capacity(types.len());
+    for word in types {
+        match type_filter(word).flatten() {
+            Some(t) => wanted.push(t),
+            // tipo que não existe: nada casa
+            None => return Vec::new(),
+        }
+    }
+    let filter_lower = if wanted.is_empty() {
+        filter.to_lowercase()
+    } else {
+        words.join(" ").to_lowercase()
+    };
+    POKEMON_LIST
+        .iter()
+        .copied()
+        .filter(|item| wanted.iter().all(|t| item.types.contains(t)))
+        .filter(|item| {
+            item.id.to_string().contains(&filter_lower)
+                || item.name.to_lowercase().contains(&filter_lower)
+        })
+        .collect()
+}
+
+/// `Some` para palavras `type:`/`tipo:`; dentro, o tipo pelo nome da PokeAPI ou em português
+fn type_filter(word: &str) -> Option<Option<PokemonType>> {
+    let (key, value) = word.split_once(':')?;
+    if !key.eq_ignore_ascii_case("type") && !key.eq_ignore_ascii_case("tipo") {
+        return None;
+    }
+    let value = value.to_lowercase();
+    Some(
+        PokemonType::ALL
+            .into_iter()
+            .find(|t| t.id() == value || type_label_pt(*t).to_lowercase() == value),
+    )
+}
+
+/// Procura um Pokémon pelo número ou pelo nome exato (ex.: "25" ou "pikachu")
+pub fn find_pokemon(query: &str) -> Option<Pokemon> {
+    let query = query.trim();
+    match query.parse::<u32>() {
+        Ok(id) => POKEMON_LIST.iter().find(|p| p.id == id).copied(),
+        Err(_) => POKEMON_LIST
+            .iter()
+            .find(|p| p.name.eq_ignore_ascii_case(query))
+            .copied(),
+    }
+}
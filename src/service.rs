@@ -1,22 +1,31 @@
+use crate::types::{PokemonType, Stat};
 use serde::Deserialize;
 
 const BASE: &str = "https://pokeapi.co/api/v2";
 
+/// Sprites padrão (96x96) do repositório da PokeAPI; a URL só depende do número
+const SPRITES: &str = "https://raw.githubusercontent.com/PokeAPI/sprites/master/sprites/pokemon";
+
+/// URL da sprite pequena, sem precisar buscar o detalhe antes
+pub fn sprite_url(id: u32) -> String {
+    format!("{SPRITES}/{id}.png")
+}
+
 #[derive(Debug, Deserialize)]
-struct NamedResource {
-    name: String,
+struct NamedResource<T = String> {
+    name: T,
 }
 
 #[derive(Debug, Deserialize)]
 struct PokemonTypeEntry {
     #[serde(rename = "type")]
-    typ: NamedResource,
+    typ: NamedResource<PokemonType>,
 }
 
 #[derive(Debug, Deserialize)]
 struct StatEntry {
     base_stat: u32,
-    stat: NamedResource,
+    stat: NamedResource<Stat>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -43,18 +52,92 @@ pub struct AbilityInfo {
 
 #[derive(Debug, Deserialize)]
 struct Sprites {
+    #[serde(flatten)]
+    base: SpriteSet,
     other: Option<Other>,
 }
 
 #[derive(Debug, Deserialize)]
 struct Other {
+    dream_world: Option<SpriteSet>,
+    home: Option<SpriteSet>,
     #[serde(rename = "official-artwork")]
-    official_artwork: Option<FrontDefault>,
+    official_artwork: Option<SpriteSet>,
+    showdown: Option<SpriteSet>,
 }
 
-#[derive(Debug, Deserialize)]
-struct FrontDefault {
-    front_default: Option<String>,
+/// URLs de um conjunto de sprites (cada fonte preenche só parte dos campos)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SpriteSet {
+    pub front_default: Option<String>,
+    pub front_female: Option<String>,
+    pub front_shiny: Option<String>,
+    pub front_shiny_female: Option<String>,
+    pub back_default: Option<String>,
+    pub back_female: Option<String>,
+    pub back_shiny: Option<String>,
+    pub back_shiny_female: Option<String>,
+}
+
+impl SpriteSet {
+    /// Frente na variante pedida, sem cair em outra variante
+    fn front(&self, variant: SpriteVariant) -> Option<&str> {
+        match (variant.shiny, variant.female) {
+            (false, false) => self.front_default.as_deref(),
+            (false, true) => self.front_female.as_deref(),
+            (true, false) => self.front_shiny.as_deref(),
+            (true, true) => self.front_shiny_female.as_deref(),
+        }
+    }
+}
+
+/// Variante de sprite escolhida na tela
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SpriteVariant {
+    pub shiny: bool,
+    pub female: bool,
+    /// Prefere as sprites animadas do Showdown (GIF)
+    pub animated: bool,
+}
+
+/// Todas as sprites de um Pokémon, por fonte
+#[derive(Debug, Clone, Default)]
+pub struct DetailSprites {
+    pub default: SpriteSet,
+    pub official_artwork: SpriteSet,
+    pub home: SpriteSet,
+    pub dream_world: SpriteSet,
+    pub showdown: SpriteSet,
+}
+
+impl DetailSprites {
+    /// Melhor imagem para a variante: (Showdown animado →) official artwork → HOME →
+    /// dream world → sprite padrão. Sem versão fêmea, cai na normal; sem shiny, não troca de cor.
+    pub fn artwork_url(&self, variant: SpriteVariant) -> Option<&str> {
+        let animated = variant.animated.then_some(&self.showdown);
+        let sources: Vec<&SpriteSet> = animated
+            .into_iter()
+            .chain([
+                &self.official_artwork,
+                &self.home,
+                &self.dream_world,
+                &self.default,
+            ])
+            .collect();
+        let find = |v: SpriteVariant| sources.iter().find_map(|s| s.front(v));
+        find(variant).or_else(|| {
+            variant
+                .female
+                .then(|| {
+                    find(SpriteVariant {
+                        female: false,
+                        ..variant
+                    })
+                })
+                .flatten()
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -62,24 +145,42 @@ pub struct Detail {
     pub id: u32,
     pub height: u32,
     pub weight: u32,
-    pub types: Vec<String>,
-    pub stats: Vec<(String, u32)>,
-    pub artwork_url: Option<String>,
+    pub types: Vec<PokemonType>,
+    pub stats: Vec<(Stat, u32)>,
+    pub sprites: DetailSprites,
     pub ability1: String,
     pub ability2: String,
     pub hidden_ability: String,
 }
 
+impl Detail {
+    /// URL da artwork padrão (official artwork, com fallback)
+    pub fn artwork_url(&self) -> Option<&str> {
+        self.sprites.artwork_url(SpriteVariant::default())
+    }
+}
+
 impl From<PokemonApiDetail> for Detail {
     fn from(v: PokemonApiDetail) -> Self {
         let (ab1, ab2, hidden) = split_abilities_str(&v);
 
-        let artwork_url = v
-            .sprites
-            .other
-            .as_ref()
-            .and_then(|o| o.official_artwork.as_ref())
-            .and_then(|oa| oa.front_default.clone());
+        let other = v.sprites.other;
+        let (official_artwork, home, dream_world, showdown) = match other {
+            Some(o) => (
+                o.official_artwork.unwrap_or_default(),
+                o.home.unwrap_or_default(),
+                o.dream_world.unwrap_or_default(),
+                o.showdown.unwrap_or_default(),
+            ),
+            None => Default::default(),
+        };
+        let sprites = DetailSprites {
+            default: v.sprites.base,
+            official_artwork,
+            home,
+            dream_world,
+            showdown,
+        };
 
         Self {
             id: v.id,
@@ -91,7 +192,7 @@ impl From<PokemonApiDetail> for Detail {
                 .into_iter()
                 .map(|s| (s.stat.name, s.base_stat))
                 .collect(),
-            artwork_url,
+            sprites,
             ability1: ab1,
             ability2: ab2,
             hidden_ability: hidden,
@@ -125,6 +226,12 @@ pub struct PokemonService {
     client: reqwest::Client,
 }
 
+impl Default for PokemonService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PokemonService {
     pub fn new() -> Self {
         Self {
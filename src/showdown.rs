@@ -0,0 +1,512 @@
+use crate::{ABILITY_NAMES, POKEMON_LIST, Pokemon, SPECIES_ABILITIES};
+use std::fmt;
+
+/// Naturezas aceitas pelo Showdown (nomes em inglês)
+const NATURES: [&str; 25] = [
+    "Hardy", "Lonely", "Brave", "Adamant", "Naughty", "Bold", "Docile", "Relaxed", "Impish", "Lax",
+    "Timid", "Hasty", "Serious", "Jolly", "Naive", "Modest", "Mild", "Quiet", "Bashful", "Rash",
+    "Calm", "Gentle", "Sassy", "Careful", "Quirky",
+];
+
+/// Tipos válidos para Tera Type
+const TERA_TYPES: [&str; 19] = [
+    "Normal", "Fire", "Water", "Electric", "Grass", "Ice", "Fighting", "Poison", "Ground",
+    "Flying", "Psychic", "Bug", "Rock", "Ghost", "Dragon", "Dark", "Steel", "Fairy", "Stellar",
+];
+
+/// Tipos aceitos em Hidden Power (sem Normal, Fairy e Stellar)
+const HIDDEN_POWER_TYPES: [&str; 16] = [
+    "Fire", "Water", "Electric", "Grass", "Ice", "Fighting", "Poison", "Ground", "Flying",
+    "Psychic", "Bug", "Rock", "Ghost", "Dragon", "Dark", "Steel",
+];
+
+/// Abreviações usadas nas linhas de EVs/IVs, na ordem HP/Atk/Def/SpA/SpD/Spe
+const STAT_KEYS: [&str; 6] = ["HP", "Atk", "Def", "SpA", "SpD", "Spe"];
+
+const MAX_TEAM: usize = 6;
+const MAX_MOVES: usize = 4;
+const MAX_EV: u16 = 252;
+const MAX_EV_TOTAL: u16 = 510;
+const MAX_IV: u16 = 31;
+const MAX_DYNAMAX_LEVEL: u8 = 10;
+
+/// Erro de importação com a linha (1-based) onde ocorreu
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "linha {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn fail<T>(line: usize, message: impl Into<String>) -> Result<T, ParseError> {
+    Err(ParseError {
+        line,
+        message: message.into(),
+    })
+}
+
+/// Valores por stat na ordem HP/Atk/Def/SpA/SpD/Spe
+pub type StatSpread = [u16; 6];
+
+/// Um Pokémon do time no formato do Showdown
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamMember {
+    pub nickname: Option<String>,
+    /// Espécie como escrita no texto (pode incluir forma, ex.: "Rotom-Wash")
+    pub species: String,
+    pub species_id: u32,
+    pub gender: Option<char>,
+    pub item: Option<String>,
+    pub ability: Option<String>,
+    pub level: Option<u8>,
+    pub shiny: bool,
+    pub happiness: Option<u8>,
+    pub pokeball: Option<String>,
+    pub hidden_power: Option<String>,
+    pub dynamax_level: Option<u8>,
+    pub gigantamax: bool,
+    pub tera_type: Option<String>,
+    pub evs: StatSpread,
+    pub ivs: StatSpread,
+    pub nature: Option<String>,
+    pub moves: Vec<String>,
+}
+
+impl TeamMember {
+    fn new(species: String, species_id: u32) -> Self {
+        Self {
+            nickname: None,
+            species,
+            species_id,
+            gender: None,
+            item: None,
+            ability: None,
+            level: None,
+            shiny: false,
+            happiness: None,
+            pokeball: None,
+            hidden_power: None,
+            dynamax_level: None,
+            gigantamax: false,
+            tera_type: None,
+            evs: [0; 6],
+            ivs: [MAX_IV; 6],
+            nature: None,
+            moves: Vec::new(),
+        }
+    }
+}
+
+/// Normaliza nomes como o Showdown (minúsculas, só letras e números);
+/// ♀/♂ viram f/m, então "Nidoran♂" e "Nidoran-M" dão o mesmo id
+fn to_id(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '♀' => 'f',
+            '♂' => 'm',
+            c => c,
+        })
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Id sem o símbolo de gênero: "Nidoran" sozinho casa com as duas espécies
+fn to_loose_id(s: &str) -> String {
+    to_id(&s.replace(['♀', '♂'], ""))
+}
+
+/// Procura a espécie pelo nome; formas ("Rotom-Wash") caem na espécie base.
+/// Um nome que serve para mais de uma espécie é erro, não a primeira encontrada.
+fn find_species(name: &str) -> Result<&'static Pokemon, String> {
+    let mut candidate = name;
+    loop {
+        let id = to_id(candidate);
+        let found: Vec<&'static Pokemon> = POKEMON_LIST
+            .iter()
+            .filter(|p| to_id(p.name) == id || to_loose_id(p.name) == id)
+            .collect();
+        match found.as_slice() {
+            [pokemon] => return Ok(pokemon),
+            [] => {}
+            many => {
+                let names: Vec<&str> = many.iter().map(|p| p.name).collect();
+                return Err(format!(
+                    "espécie ambígua: \"{name}\" ({})",
+                    names.join(", ")
+                ));
+            }
+        }
+        match candidate.rfind('-') {
+            Some(dash) => candidate = &candidate[..dash],
+            None => return Err(format!("espécie desconhecida: \"{name}\"")),
+        }
+    }
+}
+
+/// Habilidades que a espécie pode ter em alguma forma
+fn species_abilities(species_id: u32) -> &'static [&'static str] {
+    let idx = (species_id as usize).wrapping_sub(1);
+    SPECIES_ABILITIES.get(idx).copied().unwrap_or_default()
+}
+
+fn find_in<'a>(list: &[&'a str], name: &str) -> Option<&'a str> {
+    let id = to_id(name);
+    list.iter().copied().find(|n| to_id(n) == id)
+}
+
+/// Lê um time completo (até 6 Pokémon separados por linhas em branco).
+/// Backups com vários times ("=== ... ===" repetido) são recusados.
+pub fn parse_team(text: &str) -> Result<Vec<TeamMember>, ParseError> {
+    let mut team = Vec::new();
+    let mut current: Option<TeamMember> = None;
+    let mut first_header: Option<usize> = None;
+
+    for (idx, raw) in text.lines().enumerate() {
+        let line_no = idx + 1;
+        let line = raw.trim();
+
+        // linha em branco ou cabeçalho de backup ("=== [gen9] Time ===") encerra o bloco
+        if line.is_empty() || line.starts_with("===") {
+            if line.starts_with("===") {
+                if let Some(first) = first_header {
+                    return fail(
+                        line_no,
+                        format!("backup com mais de um time (o primeiro na linha {first})"),
+                    );
+                }
+                first_header = Some(line_no);
+            }
+            if let Some(member) = current.take() {
+                team.push(member);
+            }
+            continue;
+        }
+
+        match current.as_mut() {
+            None => {
+                if team.len() == MAX_TEAM {
+                    return fail(line_no, format!("o time tem mais de {MAX_TEAM} Pokémon"));
+                }
+                current = Some(parse_header(line, line_no)?);
+            }
+            Some(member) => parse_attribute(member, line, line_no)?,
+        }
+    }
+    if let Some(member) = current {
+        team.push(member);
+    }
+    Ok(team)
+}
+
+/// "Apelido (Espécie) (M) @ Item"
+fn parse_header(line: &str, line_no: usize) -> Result<TeamMember, ParseError> {
+    let (left, item) = match line.split_once(" @ ") {
+        Some((l, i)) => (l.trim(), Some(i.trim())),
+        None => (line, None),
+    };
+
+    let (left, gender) = if let Some(l) = left.strip_suffix("(M)") {
+        (l.trim_end(), Some('M'))
+    } else if let Some(l) = left.strip_suffix("(F)") {
+        (l.trim_end(), Some('F'))
+    } else {
+        (left, None)
+    };
+
+    let (nickname, species) = match (left.strip_suffix(')'), left.rfind(" (")) {
+        (Some(inner), Some(open)) => (Some(left[..open].trim()), inner[open + 2..].trim()),
+        _ => (None, left),
+    };
+
+    if species.is_empty() {
+        return fail(line_no, "espécie ausente");
+    }
+    let pokemon = match find_species(species) {
+        Ok(p) => p,
+        Err(message) => return fail(line_no, message),
+    };
+
+    let mut member = TeamMember::new(species.to_string(), pokemon.id);
+    member.nickname = nickname.filter(|n| !n.is_empty()).map(str::to_string);
+    member.gender = gender;
+    member.item = item.filter(|i| !i.is_empty()).map(str::to_string);
+    Ok(member)
+}
+
+fn parse_attribute(member: &mut TeamMember, line: &str, line_no: usize) -> Result<(), ParseError> {
+    if let Some(mv) = line.strip_prefix('-') {
+        let mv = mv.trim();
+        if mv.is_empty() {
+            return fail(line_no, "golpe vazio");
+        }
+        if member.moves.len() == MAX_MOVES {
+            return fail(line_no, format!("mais de {MAX_MOVES} golpes"));
+        }
+        member.moves.push(mv.to_string());
+        return Ok(());
+    }
+
+    if let Some(nature) = line.strip_suffix("Nature") {
+        let nature = nature.trim();
+        return match find_in(&NATURES, nature) {
+            Some(n) => {
+                member.nature = Some(n.to_string());
+                Ok(())
+            }
+            None => fail(line_no, format!("natureza desconhecida: \"{nature}\"")),
+        };
+    }
+
+    let Some((key, value)) = line.split_once(':') else {
+        return fail(line_no, format!("linha não reconhecida: \"{line}\""));
+    };
+    let value = value.trim();
+    match key.trim() {
+        "Ability" => {
+            let Some(ability) = find_in(ABILITY_NAMES, value) else {
+                return fail(line_no, format!("habilidade desconhecida: \"{value}\""));
+            };
+            // sem dados da espécie (lista vazia) a habilidade não é conferida
+            let allowed = species_abilities(member.species_id);
+            if !allowed.is_empty() && !allowed.contains(&ability) {
+                return fail(
+                    line_no,
+                    format!("{} não tem a habilidade {ability}", member.species),
+                );
+            }
+            member.ability = Some(ability.to_string());
+        }
+        "Level" => match value.parse::<u8>() {
+            Ok(l) if (1..=100).contains(&l) => member.level = Some(l),
+            _ => return fail(line_no, format!("nível inválido: \"{value}\"")),
+        },
+        "Shiny" => member.shiny = value.eq_ignore_ascii_case("yes"),
+        "Happiness" => match value.parse::<u8>() {
+            Ok(h) => member.happiness = Some(h),
+            Err(_) => return fail(line_no, format!("felicidade inválida: \"{value}\"")),
+        },
+        "Pokeball" => member.pokeball = Some(value.to_string()).filter(|b| !b.is_empty()),
+        "Hidden Power" => match find_in(&HIDDEN_POWER_TYPES, value) {
+            Some(t) => member.hidden_power = Some(t.to_string()),
+            None => return fail(line_no, format!("hidden power desconhecido: \"{value}\"")),
+        },
+        "Dynamax Level" => match value.parse::<u8>() {
+            Ok(l) if l <= MAX_DYNAMAX_LEVEL => member.dynamax_level = Some(l),
+            _ => return fail(line_no, format!("nível de Dynamax inválido: \"{value}\"")),
+        },
+        "Gigantamax" => member.gigantamax = value.eq_ignore_ascii_case("yes"),
+        "Tera Type" => match find_in(&TERA_TYPES, value) {
+            Some(t) => member.tera_type = Some(t.to_string()),
+            None => return fail(line_no, format!("tera type desconhecido: \"{value}\"")),
+        },
+        "EVs" => {
+            member.evs = parse_spread(value, [0; 6], MAX_EV, line_no)?;
+            let total: u16 = member.evs.iter().sum();
+            if total > MAX_EV_TOTAL {
+                return fail(
+                    line_no,
+                    format!("total de EVs {total} passa de {MAX_EV_TOTAL}"),
+                );
+            }
+        }
+        "IVs" => member.ivs = parse_spread(value, [MAX_IV; 6], MAX_IV, line_no)?,
+        // atributos novos do Showdown não impedem a importação; só não são guardados
+        _ => {}
+    }
+    Ok(())
+}
+
+/// "252 Atk / 4 SpD / 252 Spe"
+fn parse_spread(
+    value: &str,
+    default: StatSpread,
+    max: u16,
+    line_no: usize,
+) -> Result<StatSpread, ParseError> {
+    let mut spread = default;
+    for part in value.split('/') {
+        let part = part.trim();
+        let Some((amount, stat)) = part.split_once(' ') else {
+            return fail(line_no, format!("valor inválido: \"{part}\""));
+        };
+        let Some(slot) = STAT_KEYS
+            .iter()
+            .position(|k| k.eq_ignore_ascii_case(stat.trim()))
+        else {
+            return fail(line_no, format!("stat desconhecido: \"{}\"", stat.trim()));
+        };
+        match amount.parse::<u16>() {
+            Ok(v) if v <= max => spread[slot] = v,
+            _ => {
+                return fail(
+                    line_no,
+                    format!("valor inválido para {}: \"{amount}\"", STAT_KEYS[slot]),
+                );
+            }
+        }
+    }
+    Ok(spread)
+}
+
+/// Gera a linha "252 Atk / 4 SpD" só com os valores diferentes do padrão
+pub fn format_spread(spread: &StatSpread, default: u16) -> Option<String> {
+    let parts: Vec<String> = spread
+        .iter()
+        .zip(STAT_KEYS)
+        .filter(|(v, _)| **v != default)
+        .map(|(v, k)| format!("{v} {k}"))
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(" / "))
+}
+
+/// Serializa um Pokémon no formato do Showdown
+pub fn format_member(member: &TeamMember) -> String {
+    let mut out = String::new();
+    match &member.nickname {
+        Some(nick) => out.push_str(&format!("{nick} ({})", member.species)),
+        None => out.push_str(&member.species),
+    }
+    if let Some(g) = member.gender {
+        out.push_str(&format!(" ({g})"));
+    }
+    if let Some(item) = &member.item {
+        out.push_str(&format!(" @ {item}"));
+    }
+    out.push('\n');
+
+    if let Some(ability) = &member.ability {
+        out.push_str(&format!("Ability: {ability}\n"));
+    }
+    if let Some(level) = member.level {
+        out.push_str(&format!("Level: {level}\n"));
+    }
+    if member.shiny {
+        out.push_str("Shiny: Yes\n");
+    }
+    if let Some(h) = member.happiness {
+        out.push_str(&format!("Happiness: {h}\n"));
+    }
+    if let Some(ball) = &member.pokeball {
+        out.push_str(&format!("Pokeball: {ball}\n"));
+    }
+    if let Some(hp) = &member.hidden_power {
+        out.push_str(&format!("Hidden Power: {hp}\n"));
+    }
+    if let Some(level) = member.dynamax_level {
+        out.push_str(&format!("Dynamax Level: {level}\n"));
+    }
+    if member.gigantamax {
+        out.push_str("Gigantamax: Yes\n");
+    }
+    if let Some(tera) = &member.tera_type {
+        out.push_str(&format!("Tera Type: {tera}\n"));
+    }
+    if let Some(evs) = format_spread(&member.evs, 0) {
+        out.push_str(&format!("EVs: {evs}\n"));
+    }
+    if let Some(nature) = &member.nature {
+        out.push_str(&format!("{nature} Nature\n"));
+    }
+    if let Some(ivs) = format_spread(&member.ivs, MAX_IV) {
+        out.push_str(&format!("IVs: {ivs}\n"));
+    }
+    for mv in &member.moves {
+        out.push_str(&format!("- {mv}\n"));
+    }
+    out
+}
+
+/// Serializa o time inteiro, um bloco por Pokémon
+pub fn format_team(team: &[TeamMember]) -> String {
+    team.iter()
+        .map(format_member)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nidoran_genders() {
+        let team = parse_team("Nidoran-M\n\nNidoran-F\n\nNidoran♂\n\nNidoran♀").unwrap();
+        let ids: Vec<u32> = team.iter().map(|m| m.species_id).collect();
+        assert_eq!(ids, [32, 29, 32, 29]);
+    }
+
+    #[test]
+    fn ambiguous_species() {
+        let err = parse_team("Nidoran").unwrap_err();
+        assert_eq!(err.line, 1);
+        assert!(
+            err.message.starts_with("espécie ambígua"),
+            "{}",
+            err.message
+        );
+    }
+
+    #[test]
+    fn extra_attributes_round_trip() {
+        let text = "Charizard @ Heavy-Duty Boots\n\
+                    Ability: Blaze\n\
+                    Level: 50\n\
+                    Pokeball: Poke Ball\n\
+                    Hidden Power: Fire\n\
+                    Dynamax Level: 5\n\
+                    Gigantamax: Yes\n\
+                    Tera Type: Dragon\n\
+                    EVs: 252 SpA / 4 SpD / 252 Spe\n\
+                    Timid Nature\n\
+                    - Flamethrower\n";
+        let team = parse_team(text).unwrap();
+        let member = &team[0];
+        assert_eq!(member.pokeball.as_deref(), Some("Poke Ball"));
+        assert_eq!(member.hidden_power.as_deref(), Some("Fire"));
+        assert_eq!(member.dynamax_level, Some(5));
+        assert!(member.gigantamax);
+        assert_eq!(format_team(&team), text);
+    }
+
+    #[test]
+    fn unknown_attribute_is_skipped() {
+        let team = parse_team("Pikachu\nSome Future Key: 3\n- Thunderbolt").unwrap();
+        assert_eq!(team[0].moves, ["Thunderbolt"]);
+    }
+
+    #[test]
+    fn ability_must_belong_to_species() {
+        let err = parse_team("Pikachu @ Light Ball\nAbility: Levitate").unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.message, "Pikachu não tem a habilidade Levitate");
+        // formas contam para a espécie: Lightning Rod é do Pikachu, Surge Surfer do Raichu de Alola
+        assert!(parse_team("Pikachu\nAbility: Lightning Rod").is_ok());
+        assert!(parse_team("Raichu-Alola\nAbility: Surge Surfer").is_ok());
+    }
+
+    #[test]
+    fn single_team_backup_header() {
+        let team = parse_team("=== [gen9] Time ===\n\nPikachu\n- Thunderbolt\n").unwrap();
+        assert_eq!(team.len(), 1);
+    }
+
+    #[test]
+    fn backup_with_several_teams_is_rejected() {
+        let text = "=== [gen9] Um ===\n\nPikachu\n\n=== [gen9] Dois ===\n\nEevee\n";
+        let err = parse_team(text).unwrap_err();
+        assert_eq!(err.line, 5);
+        assert!(
+            err.message.starts_with("backup com mais de um time"),
+            "{}",
+            err.message
+        );
+    }
+}
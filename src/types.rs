@@ -0,0 +1,169 @@
+use serde::{Deserialize, Serialize};
+
+/// Tipos elementais (nomes da PokeAPI em minúsculas)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PokemonType {
+    Normal,
+    Fire,
+    Water,
+    Electric,
+    Grass,
+    Ice,
+    Fighting,
+    Poison,
+    Ground,
+    Flying,
+    Psychic,
+    Bug,
+    Rock,
+    Ghost,
+    Dragon,
+    Dark,
+    Steel,
+    Fairy,
+}
+
+impl PokemonType {
+    pub const ALL: [PokemonType; 18] = [
+        PokemonType::Normal,
+        PokemonType::Fire,
+        PokemonType::Water,
+        PokemonType::Electric,
+        PokemonType::Grass,
+        PokemonType::Ice,
+        PokemonType::Fighting,
+        PokemonType::Poison,
+        PokemonType::Ground,
+        PokemonType::Flying,
+        PokemonType::Psychic,
+        PokemonType::Bug,
+        PokemonType::Rock,
+        PokemonType::Ghost,
+        PokemonType::Dragon,
+        PokemonType::Dark,
+        PokemonType::Steel,
+        PokemonType::Fairy,
+    ];
+
+    /// Nome na PokeAPI (também o nome do ícone em `imagens/tipos/`)
+    pub fn id(self) -> &'static str {
+        match self {
+            PokemonType::Normal => "normal",
+            PokemonType::Fire => "fire",
+            PokemonType::Water => "water",
+            PokemonType::Electric => "electric",
+            PokemonType::Grass => "grass",
+            PokemonType::Ice => "ice",
+            PokemonType::Fighting => "fighting",
+            PokemonType::Poison => "poison",
+            PokemonType::Ground => "ground",
+            PokemonType::Flying => "flying",
+            PokemonType::Psychic => "psychic",
+            PokemonType::Bug => "bug",
+            PokemonType::Rock => "rock",
+            PokemonType::Ghost => "ghost",
+            PokemonType::Dragon => "dragon",
+            PokemonType::Dark => "dark",
+            PokemonType::Steel => "steel",
+            PokemonType::Fairy => "fairy",
+        }
+    }
+
+    /// Multiplicador do ataque deste tipo contra um tipo defensor (tabela da Gen VI em diante)
+    pub fn effectiveness(self, defender: PokemonType) -> f32 {
+        use PokemonType::*;
+        let (double, half, immune): (&[PokemonType], &[PokemonType], &[PokemonType]) = match self {
+            Normal => (&[], &[Rock, Steel], &[Ghost]),
+            Fire => (&[Grass, Ice, Bug, Steel], &[Fire, Water, Rock, Dragon], &[]),
+            Water => (&[Fire, Ground, Rock], &[Water, Grass, Dragon], &[]),
+            Electric => (&[Water, Flying], &[Electric, Grass, Dragon], &[Ground]),
+            Grass => (
+                &[Water, Ground, Rock],
+                &[Fire, Grass, Poison, Flying, Bug, Dragon, Steel],
+                &[],
+            ),
+            Ice => (
+                &[Grass, Ground, Flying, Dragon],
+                &[Fire, Water, Ice, Steel],
+                &[],
+            ),
+            Fighting => (
+                &[Normal, Ice, Rock, Dark, Steel],
+                &[Poison, Flying, Psychic, Bug, Fairy],
+                &[Ghost],
+            ),
+            Poison => (&[Grass, Fairy], &[Poison, Ground, Rock, Ghost], &[Steel]),
+            Ground => (
+                &[Fire, Electric, Poison, Rock, Steel],
+                &[Grass, Bug],
+                &[Flying],
+            ),
+            Flying => (&[Grass, Fighting, Bug], &[Electric, Rock, Steel], &[]),
+            Psychic => (&[Fighting, Poison], &[Psychic, Steel], &[Dark]),
+            Bug => (
+                &[Grass, Psychic, Dark],
+                &[Fire, Fighting, Poison, Flying, Ghost, Steel, Fairy],
+                &[],
+            ),
+            Rock => (&[Fire, Ice, Flying, Bug], &[Fighting, Ground, Steel], &[]),
+            Ghost => (&[Psychic, Ghost], &[Dark], &[Normal]),
+            Dragon => (&[Dragon], &[Steel], &[Fairy]),
+            Dark => (&[Psychic, Ghost], &[Fighting, Dark, Fairy], &[]),
+            Steel => (&[Ice, Rock, Fairy], &[Fire, Water, Electric, Steel], &[]),
+            Fairy => (&[Fighting, Dragon, Dark], &[Fire, Poison, Steel], &[]),
+        };
+        if immune.contains(&defender) {
+            0.0
+        } else if double.contains(&defender) {
+            2.0
+        } else if half.contains(&defender) {
+            0.5
+        } else {
+            1.0
+        }
+    }
+
+    /// Multiplicador contra um Pokémon de um ou dois tipos
+    pub fn effectiveness_against(self, defenders: &[PokemonType]) -> f32 {
+        defenders.iter().map(|d| self.effectiveness(*d)).product()
+    }
+}
+
+/// Stats base, na ordem da PokeAPI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Stat {
+    Hp,
+    Attack,
+    Defense,
+    SpecialAttack,
+    SpecialDefense,
+    Speed,
+}
+
+impl Stat {
+    pub const ALL: [Stat; 6] = [
+        Stat::Hp,
+        Stat::Attack,
+        Stat::Defense,
+        Stat::SpecialAttack,
+        Stat::SpecialDefense,
+        Stat::Speed,
+    ];
+
+    /// Nome na PokeAPI
+    pub fn id(self) -> &'static str {
+        match self {
+            Stat::Hp => "hp",
+            Stat::Attack => "attack",
+            Stat::Defense => "defense",
+            Stat::SpecialAttack => "special-attack",
+            Stat::SpecialDefense => "special-defense",
+            Stat::Speed => "speed",
+        }
+    }
+}
+
+// enum PokemonColor, gerado a partir de data/pokemon_colors.csv
+include!(concat!(env!("OUT_DIR"), "/pokemon_color.rs"));
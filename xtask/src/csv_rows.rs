@@ -0,0 +1,103 @@
+//! Leitura dos CSVs de `data/`, compartilhada entre o `build.rs` e o xtask
+//! (o `build.rs` inclui este arquivo com `#[path]`)
+
+// Cada um usa só parte das funções (o xtask não lê arquivos sem cabeçalho)
+#![allow(dead_code)]
+
+use std::{collections::HashMap, path::Path};
+
+/// Uma linha do CSV com o número da linha no arquivo (para mensagens de erro)
+pub struct Row<'a> {
+    path: &'a str,
+    line: u64,
+    record: csv::StringRecord,
+    columns: &'a HashMap<String, usize>,
+}
+
+impl Row<'_> {
+    pub fn err(&self, msg: impl std::fmt::Display) -> String {
+        format!("{}:{}: {msg}", self.path, self.line)
+    }
+
+    /// Campo pela posição (arquivos sem cabeçalho)
+    pub fn at(&self, idx: usize) -> Result<&str, String> {
+        self.record
+            .get(idx)
+            .map(str::trim)
+            .ok_or_else(|| self.err(format!("coluna {} ausente", idx + 1)))
+    }
+
+    /// Campo pelo nome da coluna do cabeçalho
+    pub fn get(&self, column: &str) -> Result<&str, String> {
+        let idx = *self
+            .columns
+            .get(column)
+            .ok_or_else(|| format!("{}: coluna \"{column}\" não existe", self.path))?;
+        self.at(idx)
+    }
+
+    pub fn parse<T: std::str::FromStr>(&self, column: &str) -> Result<T, String> {
+        self.parse_value(self.get(column)?, column)
+    }
+
+    /// Igual a `parse`, pela posição
+    pub fn parse_at<T: std::str::FromStr>(&self, idx: usize, what: &str) -> Result<T, String> {
+        self.parse_value(self.at(idx)?, what)
+    }
+
+    /// Igual a `parse`, mas campo vazio vira `None`
+    pub fn parse_opt<T: std::str::FromStr>(&self, column: &str) -> Result<Option<T>, String> {
+        if self.get(column)?.is_empty() {
+            return Ok(None);
+        }
+        self.parse(column).map(Some)
+    }
+
+    fn parse_value<T: std::str::FromStr>(&self, value: &str, what: &str) -> Result<T, String> {
+        value
+            .parse()
+            .map_err(|_| self.err(format!("{what} inválido: \"{value}\"")))
+    }
+}
+
+/// Lê cada linha do arquivo; aceita campos entre aspas com vírgulas, aspas e quebras de linha.
+/// Os erros citam o arquivo como `data/<nome>`, de onde quer que seja lido
+pub fn for_each_row(
+    file: impl AsRef<Path>,
+    has_headers: bool,
+    mut f: impl FnMut(&Row) -> Result<(), String>,
+) -> Result<(), String> {
+    let file = file.as_ref();
+    let name = file.file_name().unwrap_or_default().to_string_lossy();
+    let path = format!("data/{name}");
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(has_headers)
+        .from_path(file)
+        .map_err(|e| format!("{path}: {e}"))?;
+
+    let columns: HashMap<String, usize> = if has_headers {
+        let headers = reader.headers().map_err(|e| format!("{path}:1: {e}"))?;
+        headers
+            .iter()
+            .enumerate()
+            .map(|(i, h)| (h.trim().to_string(), i))
+            .collect()
+    } else {
+        HashMap::new()
+    };
+
+    for record in reader.records() {
+        let record = record.map_err(|e| match e.position() {
+            Some(pos) => format!("{path}:{}: {e}", pos.line()),
+            None => format!("{path}: {e}"),
+        })?;
+        let line = record.position().map(|p| p.line()).unwrap_or_default();
+        f(&Row {
+            path: &path,
+            line,
+            record,
+            columns: &columns,
+        })?;
+    }
+    Ok(())
+}
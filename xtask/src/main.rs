@@ -0,0 +1,296 @@
+//! Tarefas de manutenção do slinDex: `cargo xtask <comando>`
+//!
+//! `data` gera os arquivos derivados dos CSVs da PokeAPI (formato veekun)
+//! em `data/`; com `--check` só confere se os arquivos commitados estão em dia.
+
+mod csv_rows;
+
+use csv_rows::for_each_row;
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+const USAGE: &str = "\
+Uso: cargo xtask <comando>
+
+Comandos:
+  data            Gera pokemon_main.csv e pokemon_planilha.json a partir dos CSVs
+  data --check    Falha se os arquivos gerados estiverem desatualizados
+  help            Mostra esta ajuda";
+
+/// Idioma dos nomes (9 = inglês)
+const ENGLISH: &str = "9";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("data") => match &args[1..] {
+            [] => data(false),
+            [flag] if flag == "--check" => data(true),
+            _ => Err(format!("argumentos inválidos para data\n\n{USAGE}")),
+        },
+        None | Some("help" | "--help" | "-h") => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        Some(other) => Err(format!("comando desconhecido: {other}\n\n{USAGE}")),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("erro: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Pasta `data/` do repositório, independente do diretório atual
+fn data_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../data")
+}
+
+// =================== Comando data ===================
+fn data(check: bool) -> Result<(), String> {
+    let dir = data_dir();
+    let tables = Tables::load(&dir)?;
+    let outputs = [
+        ("pokemon_main.csv", pokemon_main(&tables)?),
+        ("pokemon_planilha.json", planilha(&tables)?),
+    ];
+
+    let mut stale = Vec::new();
+    for (name, content) in outputs {
+        let path = dir.join(name);
+        let current = std::fs::read(&path).unwrap_or_default();
+        if current == content.as_bytes() {
+            continue;
+        }
+        if check {
+            stale.push(name);
+        } else {
+            std::fs::write(&path, content).map_err(|e| format!("{}: {e}", path.display()))?;
+            println!("gerado: data/{name}");
+        }
+    }
+
+    if !stale.is_empty() {
+        return Err(format!(
+            "arquivos desatualizados: {} (rode `cargo xtask data`)",
+            stale.join(", ")
+        ));
+    }
+    if check {
+        println!("dados em dia");
+    }
+    Ok(())
+}
+
+// =================== Tabelas de origem ===================
+struct Species {
+    id: u32,
+    identifier: String,
+    color_id: u32,
+}
+
+/// Habilidades por slot (ids de ability)
+#[derive(Default)]
+struct Abilities {
+    first: Option<u32>,
+    second: Option<u32>,
+    hidden: Option<u32>,
+}
+
+/// Altura (dm) e peso (hg) de uma forma
+type Size = (Option<u32>, Option<u32>);
+
+/// Dados brutos já indexados
+struct Tables {
+    species: Vec<Species>,
+    species_names: HashMap<u32, String>,
+    colors: HashMap<u32, String>,
+    /// (altura, peso) de cada forma, na ordem de pokemon.csv
+    forms: HashMap<u32, Vec<Size>>,
+    /// HP/Atk/Def/SpA/SpD/Spe por pokemon_id
+    stats: HashMap<u32, [Option<u32>; 6]>,
+    abilities: HashMap<u32, Abilities>,
+    ability_names: HashMap<u32, String>,
+}
+
+impl Tables {
+    fn load(dir: &Path) -> Result<Self, String> {
+        let mut species = Vec::new();
+        for_each_row(dir.join("pokemon_species.csv"), true, |row| {
+            species.push(Species {
+                id: row.parse("id")?,
+                identifier: row.get("identifier")?.to_string(),
+                color_id: row.parse("color_id")?,
+            });
+            Ok(())
+        })?;
+
+        let mut species_names = HashMap::new();
+        for_each_row(dir.join("pokemon_species_names.csv"), true, |row| {
+            if row.get("local_language_id")? == ENGLISH {
+                species_names.insert(row.parse("pokemon_species_id")?, row.get("name")?.into());
+            }
+            Ok(())
+        })?;
+
+        let mut colors = HashMap::new();
+        for_each_row(dir.join("pokemon_colors.csv"), true, |row| {
+            colors.insert(row.parse("id")?, row.get("identifier")?.into());
+            Ok(())
+        })?;
+
+        let mut forms: HashMap<u32, Vec<_>> = HashMap::new();
+        for_each_row(dir.join("pokemon.csv"), true, |row| {
+            forms
+                .entry(row.parse("species_id")?)
+                .or_default()
+                .push((row.parse_opt("height")?, row.parse_opt("weight")?));
+            Ok(())
+        })?;
+
+        let mut stats: HashMap<u32, [Option<u32>; 6]> = HashMap::new();
+        for_each_row(dir.join("pokemon_stats.csv"), true, |row| {
+            let stat: usize = row.parse("stat_id")?;
+            if !(1..=6).contains(&stat) {
+                return Err(row.err(format!("stat_id fora de 1..=6: {stat}")));
+            }
+            stats.entry(row.parse("pokemon_id")?).or_default()[stat - 1] =
+                Some(row.parse("base_stat")?);
+            Ok(())
+        })?;
+
+        let mut abilities: HashMap<u32, Abilities> = HashMap::new();
+        for_each_row(dir.join("pokemon_abilities.csv"), true, |row| {
+            let entry = abilities.entry(row.parse("pokemon_id")?).or_default();
+            let ability = Some(row.parse("ability_id")?);
+            match row.get("slot")? {
+                "1" => entry.first = entry.first.or(ability),
+                "2" => entry.second = entry.second.or(ability),
+                _ => {}
+            }
+            if row.get("is_hidden")? == "1" {
+                entry.hidden = entry.hidden.or(ability);
+            }
+            Ok(())
+        })?;
+
+        let mut ability_names = HashMap::new();
+        for_each_row(dir.join("ability_names.csv"), true, |row| {
+            if row.get("local_language_id")? == ENGLISH {
+                ability_names.insert(row.parse("ability_id")?, row.get("name")?.into());
+            }
+            Ok(())
+        })?;
+
+        Ok(Self {
+            species,
+            species_names,
+            colors,
+            forms,
+            stats,
+            abilities,
+            ability_names,
+        })
+    }
+
+    /// Nome em inglês; sem tradução, cai no identificador
+    fn name<'a>(&'a self, species: &'a Species) -> &'a str {
+        self.species_names
+            .get(&species.id)
+            .map_or(&species.identifier, String::as_str)
+    }
+
+    fn ability_name(&self, id: Option<u32>) -> String {
+        id.and_then(|id| self.ability_names.get(&id))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+// =================== Saídas ===================
+/// `id,nome,cor` por espécie (lido pelo build.rs).
+/// O apóstrofo tipográfico vira `'` para a busca funcionar digitando pelo teclado.
+fn pokemon_main(tables: &Tables) -> Result<String, String> {
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .terminator(csv::Terminator::Any(b'\n'))
+        .from_writer(Vec::new());
+    for species in &tables.species {
+        let id = species.id.to_string();
+        let name = tables.name(species).replace('’', "'");
+        let color = species.color_id.to_string();
+        writer
+            .write_record([id.as_str(), name.as_str(), color.as_str()])
+            .map_err(|e| e.to_string())?;
+    }
+    let bytes = writer.into_inner().map_err(|e| e.to_string())?;
+    String::from_utf8(bytes).map_err(|e| e.to_string())
+}
+
+/// Uma linha da planilha: uma por forma de cada espécie
+#[derive(Serialize)]
+struct PlanilhaRow {
+    species_id: u32,
+    name: String,
+    #[serde(rename = "HP")]
+    hp: Option<u32>,
+    #[serde(rename = "Atk")]
+    atk: Option<u32>,
+    #[serde(rename = "Def")]
+    def: Option<u32>,
+    #[serde(rename = "SpAtk")]
+    sp_atk: Option<u32>,
+    #[serde(rename = "SpDef")]
+    sp_def: Option<u32>,
+    #[serde(rename = "Speed")]
+    speed: Option<u32>,
+    ability1: String,
+    ability2: String,
+    hidden: String,
+    weight_kg: Option<f64>,
+    height_m: Option<f64>,
+    color: String,
+}
+
+/// Status e habilidades da forma padrão, com altura/peso de cada forma
+fn planilha(tables: &Tables) -> Result<String, String> {
+    let mut rows = Vec::new();
+    for species in &tables.species {
+        // espécies com cor desconhecida ficam de fora
+        let Some(color) = tables.colors.get(&species.color_id) else {
+            continue;
+        };
+        let [hp, atk, def, sp_atk, sp_def, speed] =
+            tables.stats.get(&species.id).copied().unwrap_or_default();
+        let abilities = tables.abilities.get(&species.id);
+        let sizes = match tables.forms.get(&species.id) {
+            Some(forms) => forms.clone(),
+            None => vec![(None, None)],
+        };
+        for (height, weight) in sizes {
+            rows.push(PlanilhaRow {
+                species_id: species.id,
+                name: tables.name(species).to_string(),
+                hp,
+                atk,
+                def,
+                sp_atk,
+                sp_def,
+                speed,
+                ability1: tables.ability_name(abilities.and_then(|a| a.first)),
+                ability2: tables.ability_name(abilities.and_then(|a| a.second)),
+                hidden: tables.ability_name(abilities.and_then(|a| a.hidden)),
+                weight_kg: weight.map(|w| w as f64 / 10.0),
+                height_m: height.map(|h| h as f64 / 10.0),
+                color: color.clone(),
+            });
+        }
+    }
+    serde_json::to_string_pretty(&rows).map_err(|e| e.to_string())
+}